@@ -11,9 +11,87 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
+/// struct for passing options to the method [`load_table`]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct LoadTableOptions {
+    /// The snapshots to load, all snapshots if not set
+    pub snapshots: Option<SnapshotsMode>,
+    /// ETag of a previously loaded version of the table. The server responds with 304 if the table didn't change since
+    pub if_none_match: Option<String>,
+}
+
+impl LoadTableOptions {
+    pub fn new() -> LoadTableOptions {
+        LoadTableOptions::default()
+    }
+
+    pub fn snapshots(mut self, snapshots: SnapshotsMode) -> Self {
+        self.snapshots = Some(snapshots);
+        self
+    }
+
+    pub fn if_none_match(mut self, etag: String) -> Self {
+        self.if_none_match = Some(etag);
+        self
+    }
+}
+
+/// struct for the results of the method [`load_table`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadTableResponse {
+    /// The table, with the `ETag` of the response
+    Loaded(crate::models::LoadTableResult),
+    /// The table didn't change since the ETag sent in `If-None-Match`
+    NotModified,
+}
+
+/// The snapshots returned in the table metadata of [`load_table`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum SnapshotsMode {
+    /// All valid snapshots
+    #[serde(rename = "all")]
+    All,
+    /// Only the snapshots referenced by branches and tags
+    #[serde(rename = "refs")]
+    Refs,
+
+}
+
+impl ToString for SnapshotsMode {
+    fn to_string(&self) -> String {
+        match self {
+            Self::All => String::from("all"),
+            Self::Refs => String::from("refs"),
+        }
+    }
+}
+
+impl Default for SnapshotsMode {
+    fn default() -> SnapshotsMode {
+        Self::All
+    }
+}
+
+
+/// struct for typed errors of method [`commit_transaction`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CommitTransactionError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status409(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status500(crate::models::ErrorModel),
+    Status502(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status504(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
 
 /// struct for typed errors of method [`create_namespace`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +123,21 @@ pub enum CreateTableError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`create_view`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateViewError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status409(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`drop_namespace`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -73,6 +166,20 @@ pub enum DropTableError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`drop_view`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DropViewError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`list_namespaces`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -101,6 +208,20 @@ pub enum ListTablesError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`list_views`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ListViewsError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`load_namespace_metadata`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -129,6 +250,48 @@ pub enum LoadTableError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`load_view`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LoadViewError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`namespace_exists`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NamespaceExistsError {
+    Status400(),
+    Status401(),
+    Status404(),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`register_table`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RegisterTableError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status409(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`rename_table`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -145,6 +308,39 @@ pub enum RenameTableError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`rename_view`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RenameViewError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status406(crate::models::ErrorModel),
+    Status409(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`replace_view`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReplaceViewError {
+    Status400(crate::models::ErrorModel),
+    Status401(crate::models::ErrorModel),
+    Status403(crate::models::ErrorModel),
+    Status404(crate::models::ErrorModel),
+    Status409(crate::models::ErrorModel),
+    Status419(crate::models::ErrorModel),
+    Status500(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status504(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`report_metrics`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -205,6 +401,39 @@ pub enum UpdateTableError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`view_exists`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ViewExistsError {
+    Status400(),
+    Status401(),
+    Status404(),
+    Status419(crate::models::ErrorModel),
+    Status503(crate::models::ErrorModel),
+    Status5XX(crate::models::ErrorModel),
+    UnknownValue(serde_json::Value),
+}
+
+
+/// Atomically commit updates to multiple tables. Either all changes of the transaction are applied or none of them. Each change must include the identifier of its table. If a requirement of any table fails, the server responds with 409 and no table is changed.
+pub async fn commit_transaction(configuration: &configuration::Configuration, prefix: &str, commit_transaction_request: crate::models::CommitTransactionRequest) -> Result<(), Error<CommitTransactionError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v1/{prefix}/transactions/commit", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.json(&commit_transaction_request);
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("commit_transaction", "/v1/{prefix}/transactions/commit").prefix(prefix).mutation(), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        Ok(())
+    } else {
+        Err(local_var_resp.into_error())
+    }
+}
 
 /// Create a namespace, with an optional set of properties. The server might also add properties, such as `last_modified_time` etc.
 pub async fn create_namespace(configuration: &configuration::Configuration, prefix: &str, create_namespace_request: Option<crate::models::CreateNamespaceRequest>) -> Result<crate::models::CreateNamespace200Response, Error<CreateNamespaceError>> {
@@ -215,29 +444,14 @@ pub async fn create_namespace(configuration: &configuration::Configuration, pref
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
     local_var_req_builder = local_var_req_builder.json(&create_namespace_request);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("create_namespace", "/v1/{prefix}/namespaces").prefix(prefix).mutation(), local_var_req_builder).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<CreateNamespaceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -250,62 +464,51 @@ pub async fn create_table(configuration: &configuration::Configuration, prefix:
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/tables", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
     local_var_req_builder = local_var_req_builder.json(&create_table_request);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("create_table", "/v1/{prefix}/namespaces/{namespace}/tables").prefix(prefix).namespace(namespace).mutation(), local_var_req_builder).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<CreateTableError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
-pub async fn drop_namespace(configuration: &configuration::Configuration, prefix: &str, namespace: &str) -> Result<(), Error<DropNamespaceError>> {
+/// Create a view in the given namespace. The view version and the schema of the view are passed in the request, the server assigns the view uuid and writes the first metadata file.
+pub async fn create_view(configuration: &configuration::Configuration, prefix: &str, namespace: &str, create_view_request: crate::models::CreateViewRequest) -> Result<crate::models::LoadViewResult, Error<CreateViewError>> {
     let local_var_configuration = configuration;
 
     let local_var_client = &local_var_configuration.client;
 
-    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/views", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.json(&create_view_request);
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("create_view", "/v1/{prefix}/namespaces/{namespace}/views").prefix(prefix).namespace(namespace).mutation(), local_var_req_builder).await?;
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
+    } else {
+        Err(local_var_resp.into_error())
     }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
+}
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+pub async fn drop_namespace(configuration: &configuration::Configuration, prefix: &str, namespace: &str) -> Result<(), Error<DropNamespaceError>> {
+    let local_var_configuration = configuration;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    let local_var_client = &local_var_configuration.client;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
+    let local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("drop_namespace", "/v1/{prefix}/namespaces/{namespace}").prefix(prefix).namespace(namespace).mutation(), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
         Ok(())
     } else {
-        let local_var_entity: Option<DropNamespaceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -321,33 +524,36 @@ pub async fn drop_table(configuration: &configuration::Configuration, prefix: &s
     if let Some(ref local_var_str) = purge_requested {
         local_var_req_builder = local_var_req_builder.query(&[("purgeRequested", &local_var_str.to_string())]);
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("drop_table", "/v1/{prefix}/namespaces/{namespace}/tables/{table}").prefix(prefix).namespace(namespace).table(table).mutation(), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        Ok(())
+    } else {
+        Err(local_var_resp.into_error())
     }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
+}
+
+/// Remove a view from the catalog
+pub async fn drop_view(configuration: &configuration::Configuration, prefix: &str, namespace: &str, view: &str) -> Result<(), Error<DropViewError>> {
+    let local_var_configuration = configuration;
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/views/{view}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), view=crate::apis::urlencode(view));
+    let local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("drop_view", "/v1/{prefix}/namespaces/{namespace}/views/{view}").prefix(prefix).namespace(namespace).table(view).mutation(), local_var_req_builder).await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+    if local_var_resp.is_success() {
         Ok(())
     } else {
-        let local_var_entity: Option<DropTableError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
 /// List all namespaces at a certain level, optionally starting from a given parent namespace. For example, if table accounting.tax.paid exists, using 'SELECT NAMESPACE IN accounting' would translate into `GET /namespaces?parent=accounting` and must return a namespace, [\"accounting\", \"tax\"]. If `parent` is not provided, all top-level namespaces should be listed.
-pub async fn list_namespaces(configuration: &configuration::Configuration, prefix: &str, parent: Option<&str>) -> Result<crate::models::ListNamespaces200Response, Error<ListNamespacesError>> {
+pub async fn list_namespaces(configuration: &configuration::Configuration, prefix: &str, parent: Option<&str>, page_token: Option<&str>, page_size: Option<i32>) -> Result<crate::models::ListNamespaces200Response, Error<ListNamespacesError>> {
     let local_var_configuration = configuration;
 
     let local_var_client = &local_var_configuration.client;
@@ -358,33 +564,24 @@ pub async fn list_namespaces(configuration: &configuration::Configuration, prefi
     if let Some(ref local_var_str) = parent {
         local_var_req_builder = local_var_req_builder.query(&[("parent", &local_var_str.to_string())]);
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    if let Some(ref local_var_str) = page_token {
+        local_var_req_builder = local_var_req_builder.query(&[("pageToken", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = page_size {
+        local_var_req_builder = local_var_req_builder.query(&[("pageSize", &local_var_str.to_string())]);
     }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("list_namespaces", "/v1/{prefix}/namespaces").prefix(prefix), local_var_req_builder).await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<ListNamespacesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
 /// Return all table identifiers under this namespace
-pub async fn list_tables(configuration: &configuration::Configuration, prefix: &str, namespace: &str) -> Result<crate::models::ListTables200Response, Error<ListTablesError>> {
+pub async fn list_tables(configuration: &configuration::Configuration, prefix: &str, namespace: &str, page_token: Option<&str>, page_size: Option<i32>) -> Result<crate::models::ListTables200Response, Error<ListTablesError>> {
     let local_var_configuration = configuration;
 
     let local_var_client = &local_var_configuration.client;
@@ -392,28 +589,44 @@ pub async fn list_tables(configuration: &configuration::Configuration, prefix: &
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/tables", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    if let Some(ref local_var_str) = page_token {
+        local_var_req_builder = local_var_req_builder.query(&[("pageToken", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = page_size {
+        local_var_req_builder = local_var_req_builder.query(&[("pageSize", &local_var_str.to_string())]);
     }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("list_tables", "/v1/{prefix}/namespaces/{namespace}/tables").prefix(prefix).namespace(namespace), local_var_req_builder).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
+    } else {
+        Err(local_var_resp.into_error())
+    }
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+/// Return all view identifiers under this namespace
+pub async fn list_views(configuration: &configuration::Configuration, prefix: &str, namespace: &str, page_token: Option<&str>, page_size: Option<i32>) -> Result<crate::models::ListTables200Response, Error<ListViewsError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/views", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_str) = page_token {
+        local_var_req_builder = local_var_req_builder.query(&[("pageToken", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = page_size {
+        local_var_req_builder = local_var_req_builder.query(&[("pageSize", &local_var_str.to_string())]);
+    }
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("list_views", "/v1/{prefix}/namespaces/{namespace}/views").prefix(prefix).namespace(namespace), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<ListTablesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -424,64 +637,99 @@ pub async fn load_namespace_metadata(configuration: &configuration::Configuratio
     let local_var_client = &local_var_configuration.client;
 
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
+    let local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("load_namespace_metadata", "/v1/{prefix}/namespaces/{namespace}").prefix(prefix).namespace(namespace), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
+    } else {
+        Err(local_var_resp.into_error())
+    }
+}
+
+/// Load a table from the catalog.  The response contains both configuration and table metadata. The configuration, if non-empty is used as additional configuration for the table that overrides catalog configuration. For example, this configuration may change the FileIO implemented used for the table.  The response also contains the table's full metadata.  The catalog configuration may contain credentials that should be used for subsequent requests for the table. The configuration key \"token\" is used to pass an access token to be used as a bearer token for table requests. Otherwise, a token may be passed using a RFC 8693 token type as a configuration key. For example, \"urn:ietf:params:oauth:token-type:jwt=<JWT-token>\".  Only the snapshots referenced by branches and tags are returned if `snapshots` is `refs`. The `ETag` of the response is returned in the `etag` field. A table that didn't change since the ETag sent in `If-None-Match` is reported as `NotModified`.
+pub async fn load_table(configuration: &configuration::Configuration, prefix: &str, namespace: &str, table: &str, load_table_options: &LoadTableOptions) -> Result<LoadTableResponse, Error<LoadTableError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/tables/{table}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), table=crate::apis::urlencode(table));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    if let Some(ref local_var_str) = load_table_options.snapshots {
+        local_var_req_builder = local_var_req_builder.query(&[("snapshots", &local_var_str.to_string())]);
     }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
+    if let Some(ref local_var_str) = load_table_options.if_none_match {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::IF_NONE_MATCH, local_var_str.as_str());
+    }
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("load_table", "/v1/{prefix}/namespaces/{namespace}/tables/{table}").prefix(prefix).namespace(namespace).table(table), local_var_req_builder).await?;
+
+    if local_var_resp.status == reqwest::StatusCode::NOT_MODIFIED && load_table_options.if_none_match.is_some() {
+        Ok(LoadTableResponse::NotModified)
+    } else if local_var_resp.is_success() {
+        let mut local_var_result: crate::models::LoadTableResult = serde_json::from_str(&local_var_resp.content)?;
+        local_var_result.etag = local_var_resp.headers.get(reqwest::header::ETAG).and_then(|x| x.to_str().ok()).map(ToOwned::to_owned);
+        Ok(LoadTableResponse::Loaded(local_var_result))
+    } else {
+        Err(local_var_resp.into_error())
+    }
+}
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+/// Load a view from the catalog.  The response contains both configuration and view metadata. The configuration, if non-empty is used as additional configuration for the view that overrides catalog configuration.  The response also contains the view's full metadata, matching the view metadata JSON file.
+pub async fn load_view(configuration: &configuration::Configuration, prefix: &str, namespace: &str, view: &str) -> Result<crate::models::LoadViewResult, Error<LoadViewError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/views/{view}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), view=crate::apis::urlencode(view));
+    let local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("load_view", "/v1/{prefix}/namespaces/{namespace}/views/{view}").prefix(prefix).namespace(namespace).table(view), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<LoadNamespaceMetadataError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
-/// Load a table from the catalog.  The response contains both configuration and table metadata. The configuration, if non-empty is used as additional configuration for the table that overrides catalog configuration. For example, this configuration may change the FileIO implemented used for the table.  The response also contains the table's full metadata.  The catalog configuration may contain credentials that should be used for subsequent requests for the table. The configuration key \"token\" is used to pass an access token to be used as a bearer token for table requests. Otherwise, a token may be passed using a RFC 8693 token type as a configuration key. For example, \"urn:ietf:params:oauth:token-type:jwt=<JWT-token>\".
-pub async fn load_table(configuration: &configuration::Configuration, prefix: &str, namespace: &str, table: &str) -> Result<crate::models::LoadTableResult, Error<LoadTableError>> {
+/// Check if a namespace exists. The response does not contain a body.
+pub async fn namespace_exists(configuration: &configuration::Configuration, prefix: &str, namespace: &str) -> Result<(), Error<NamespaceExistsError>> {
     let local_var_configuration = configuration;
 
     let local_var_client = &local_var_configuration.client;
 
-    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/tables/{table}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), table=crate::apis::urlencode(table));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
+    let local_var_req_builder = local_var_client.request(reqwest::Method::HEAD, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("namespace_exists", "/v1/{prefix}/namespaces/{namespace}").prefix(prefix).namespace(namespace), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        Ok(())
+    } else {
+        Err(local_var_resp.into_error())
     }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
+}
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+/// Register a table in the given namespace using the given metadata file location.
+pub async fn register_table(configuration: &configuration::Configuration, prefix: &str, namespace: &str, register_table_request: crate::models::RegisterTableRequest) -> Result<crate::models::LoadTableResult, Error<RegisterTableError>> {
+    let local_var_configuration = configuration;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    let local_var_client = &local_var_configuration.client;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/register", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.json(&register_table_request);
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("register_table", "/v1/{prefix}/namespaces/{namespace}/register").prefix(prefix).namespace(namespace).mutation(), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<LoadTableError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -494,29 +742,54 @@ pub async fn rename_table(configuration: &configuration::Configuration, prefix:
     let local_var_uri_str = format!("{}/v1/{prefix}/tables/rename", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
     local_var_req_builder = local_var_req_builder.json(&rename_table_request);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("rename_table", "/v1/{prefix}/tables/rename").prefix(prefix).mutation(), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        Ok(())
+    } else {
+        Err(local_var_resp.into_error())
+    }
+}
+
+/// Rename a view from one identifier to another. It's valid to move a view across namespaces, but the server implementation is not required to support it.
+pub async fn rename_view(configuration: &configuration::Configuration, prefix: &str, rename_table_request: crate::models::RenameTableRequest) -> Result<(), Error<RenameViewError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v1/{prefix}/views/rename", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.json(&rename_table_request);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("rename_view", "/v1/{prefix}/views/rename").prefix(prefix).mutation(), local_var_req_builder).await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+    if local_var_resp.is_success() {
         Ok(())
     } else {
-        let local_var_entity: Option<RenameTableError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
+    }
+}
+
+/// Commit updates to a view.  Commits have two parts, requirements and updates. Requirements are assertions that will be validated before attempting to make and commit changes. For example, `assert-view-uuid` will check that the view was not replaced concurrently.  Updates are changes to make to view metadata. For example, a commit may add a new view version and set it as current.
+pub async fn replace_view(configuration: &configuration::Configuration, prefix: &str, namespace: &str, view: &str, commit_view_request: crate::models::CommitViewRequest) -> Result<crate::models::LoadViewResult, Error<ReplaceViewError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/views/{view}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), view=crate::apis::urlencode(view));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.json(&commit_view_request);
+
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("replace_view", "/v1/{prefix}/namespaces/{namespace}/views/{view}").prefix(prefix).namespace(namespace).table(view).mutation(), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
+    } else {
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -528,29 +801,14 @@ pub async fn report_metrics(configuration: &configuration::Configuration, prefix
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/tables/{table}/metrics", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), table=crate::apis::urlencode(table));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
     local_var_req_builder = local_var_req_builder.json(&report_metrics_request);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("report_metrics", "/v1/{prefix}/namespaces/{namespace}/tables/{table}/metrics").prefix(prefix).namespace(namespace).table(table), local_var_req_builder).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+    if local_var_resp.is_success() {
         Ok(())
     } else {
-        let local_var_entity: Option<ReportMetricsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -561,30 +819,14 @@ pub async fn table_exists(configuration: &configuration::Configuration, prefix:
     let local_var_client = &local_var_configuration.client;
 
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/tables/{table}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), table=crate::apis::urlencode(table));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::HEAD, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
+    let local_var_req_builder = local_var_client.request(reqwest::Method::HEAD, local_var_uri_str.as_str());
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("table_exists", "/v1/{prefix}/namespaces/{namespace}/tables/{table}").prefix(prefix).namespace(namespace).table(table), local_var_req_builder).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+    if local_var_resp.is_success() {
         Ok(())
     } else {
-        let local_var_entity: Option<TableExistsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -597,29 +839,14 @@ pub async fn update_properties(configuration: &configuration::Configuration, pre
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/properties", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
     local_var_req_builder = local_var_req_builder.json(&update_namespace_properties_request);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("update_properties", "/v1/{prefix}/namespaces/{namespace}/properties").prefix(prefix).namespace(namespace).mutation(), local_var_req_builder).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<UpdatePropertiesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
@@ -632,29 +859,32 @@ pub async fn update_table(configuration: &configuration::Configuration, prefix:
     let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/tables/{table}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), table=crate::apis::urlencode(table));
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
     local_var_req_builder = local_var_req_builder.json(&commit_table_request);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("update_table", "/v1/{prefix}/namespaces/{namespace}/tables/{table}").prefix(prefix).namespace(namespace).table(table).mutation(), local_var_req_builder).await?;
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    if local_var_resp.is_success() {
+        serde_json::from_str(&local_var_resp.content).map_err(Error::from)
+    } else {
+        Err(local_var_resp.into_error())
+    }
+}
+
+/// Check if a view exists within a given namespace. This request does not return a response body.
+pub async fn view_exists(configuration: &configuration::Configuration, prefix: &str, namespace: &str, view: &str) -> Result<(), Error<ViewExistsError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v1/{prefix}/namespaces/{namespace}/views/{view}", local_var_configuration.base_path, prefix=crate::apis::urlencode(prefix), namespace=crate::apis::urlencode(namespace), view=crate::apis::urlencode(view));
+    let local_var_req_builder = local_var_client.request(reqwest::Method::HEAD, local_var_uri_str.as_str());
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("view_exists", "/v1/{prefix}/namespaces/{namespace}/views/{view}").prefix(prefix).namespace(namespace).table(view), local_var_req_builder).await?;
+
+    if local_var_resp.is_success() {
+        Ok(())
     } else {
-        let local_var_entity: Option<UpdateTableError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        Err(local_var_resp.into_error())
     }
 }
 
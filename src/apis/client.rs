@@ -0,0 +1,184 @@
+use std::{collections::HashMap, time::Duration};
+
+use super::{interceptor::BoxError, proxy::ProxyOptions, tls::TlsOptions};
+
+/// Catalog property for the timeout to establish a connection, in milliseconds.
+/// Defaults to [`DEFAULT_CONNECTION_TIMEOUT`].
+pub const CONNECTION_TIMEOUT_MS: &str = "rest.client.connection-timeout-ms";
+/// Catalog property for the timeout of a whole request, in milliseconds.
+/// Defaults to [`DEFAULT_REQUEST_TIMEOUT`].
+pub const REQUEST_TIMEOUT_MS: &str = "rest.client.request-timeout-ms";
+/// Timeout to establish a connection if [`CONNECTION_TIMEOUT_MS`] is not set, 10 seconds.
+pub const DEFAULT_CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+/// Timeout of a whole request if [`REQUEST_TIMEOUT_MS`] is not set, 60 seconds.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+/// Catalog property for how long idle connections are kept in the pool, in milliseconds.
+pub const POOL_IDLE_TIMEOUT_MS: &str = "rest.client.pool-idle-timeout-ms";
+/// Catalog property for the maximum number of idle connections per host.
+pub const POOL_MAX_IDLE_PER_HOST: &str = "rest.client.pool-max-idle-per-host";
+/// Catalog property for the TCP keepalive interval, in milliseconds.
+pub const TCP_KEEPALIVE_MS: &str = "rest.client.tcp-keepalive-ms";
+/// Catalog property to use HTTP/2 without negotiating it first.
+pub const HTTP2_PRIOR_KNOWLEDGE: &str = "rest.client.http2-prior-knowledge";
+
+/// Settings of the http client used for all catalog requests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientOptions {
+    pub connect_timeout: Option<Duration>,
+    /// Default timeout of a request, from connecting until the response body has been read.
+    pub timeout: Option<Duration>,
+    pub pool_idle_timeout: Option<Duration>,
+    pub pool_max_idle_per_host: Option<usize>,
+    pub tcp_keepalive: Option<Duration>,
+    pub http2_prior_knowledge: bool,
+    /// Request timeouts for single operations, like `update_table`, that replace the default timeout.
+    pub operation_timeouts: HashMap<String, Duration>,
+    pub tls: TlsOptions,
+    pub proxy: ProxyOptions,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        ClientOptions {
+            connect_timeout: Some(DEFAULT_CONNECTION_TIMEOUT),
+            timeout: Some(DEFAULT_REQUEST_TIMEOUT),
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            http2_prior_knowledge: false,
+            operation_timeouts: HashMap::new(),
+            tls: TlsOptions::default(),
+            proxy: ProxyOptions::default(),
+        }
+    }
+}
+
+impl ClientOptions {
+    /// Read the client options from catalog properties.
+    ///
+    /// The timeout of a single operation is set with `rest.client.request-timeout-ms.<operation>`,
+    /// for example `rest.client.request-timeout-ms.update_table`.
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self, String> {
+        let operation_timeouts = properties
+            .iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix(REQUEST_TIMEOUT_MS)
+                    .and_then(|x| x.strip_prefix('.'))
+                    .map(|operation| {
+                        parse_millis(key, value).map(|timeout| (operation.to_owned(), timeout))
+                    })
+            })
+            .collect::<Result<HashMap<String, Duration>, String>>()?;
+        Ok(ClientOptions {
+            connect_timeout: properties
+                .get(CONNECTION_TIMEOUT_MS)
+                .map(|x| parse_millis(CONNECTION_TIMEOUT_MS, x))
+                .transpose()?
+                .or(Some(DEFAULT_CONNECTION_TIMEOUT)),
+            timeout: properties
+                .get(REQUEST_TIMEOUT_MS)
+                .map(|x| parse_millis(REQUEST_TIMEOUT_MS, x))
+                .transpose()?
+                .or(Some(DEFAULT_REQUEST_TIMEOUT)),
+            pool_idle_timeout: properties
+                .get(POOL_IDLE_TIMEOUT_MS)
+                .map(|x| parse_millis(POOL_IDLE_TIMEOUT_MS, x))
+                .transpose()?,
+            pool_max_idle_per_host: properties
+                .get(POOL_MAX_IDLE_PER_HOST)
+                .map(|x| {
+                    x.parse::<usize>().map_err(|err| {
+                        format!("Invalid value {} for property {}: {}", x, POOL_MAX_IDLE_PER_HOST, err)
+                    })
+                })
+                .transpose()?,
+            tcp_keepalive: properties
+                .get(TCP_KEEPALIVE_MS)
+                .map(|x| parse_millis(TCP_KEEPALIVE_MS, x))
+                .transpose()?,
+            http2_prior_knowledge: properties
+                .get(HTTP2_PRIOR_KNOWLEDGE)
+                .map(|x| x.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            operation_timeouts,
+            tls: TlsOptions::from_properties(properties),
+            proxy: ProxyOptions::from_properties(properties),
+        })
+    }
+
+    /// Apply the options to a client builder.
+    pub fn apply(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, BoxError> {
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        let builder = self.proxy.apply(builder)?;
+        self.tls.apply(builder)
+    }
+
+    /// Build a client for the catalog at `base_path`. Returns the client together with the base path
+    /// requests have to be sent to, which differs from `base_path` if a TLS hostname override is configured.
+    pub fn build_client(&self, base_path: &str) -> Result<(reqwest::Client, String), BoxError> {
+        let builder = self.apply(reqwest::Client::builder())?;
+        let (builder, base_path) = self.tls.apply_hostname_override(builder, base_path)?;
+        Ok((builder.build()?, base_path))
+    }
+}
+
+fn parse_millis(key: &str, value: &str) -> Result<Duration, String> {
+    value
+        .parse::<u64>()
+        .map(Duration::from_millis)
+        .map_err(|err| format!("Invalid value {} for property {}: {}", value, key, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use super::{ClientOptions, DEFAULT_CONNECTION_TIMEOUT, DEFAULT_REQUEST_TIMEOUT};
+
+    #[test]
+    fn test_from_properties() {
+        let properties = HashMap::from([
+            ("rest.client.connection-timeout-ms".to_owned(), "1000".to_owned()),
+            ("rest.client.request-timeout-ms".to_owned(), "5000".to_owned()),
+            ("rest.client.request-timeout-ms.update_table".to_owned(), "60000".to_owned()),
+        ]);
+        let options = ClientOptions::from_properties(&properties).unwrap();
+        assert_eq!(options.connect_timeout, Some(Duration::from_secs(1)));
+        assert_eq!(options.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(
+            options.operation_timeouts.get("update_table"),
+            Some(&Duration::from_secs(60))
+        );
+
+        let properties = HashMap::from([(
+            "rest.client.connection-timeout-ms".to_owned(),
+            "one second".to_owned(),
+        )]);
+        ClientOptions::from_properties(&properties).expect_err("Invalid timeout");
+
+        let options = ClientOptions::from_properties(&HashMap::new()).unwrap();
+        assert_eq!(options.connect_timeout, Some(DEFAULT_CONNECTION_TIMEOUT));
+        assert_eq!(options.timeout, Some(DEFAULT_REQUEST_TIMEOUT));
+        assert_eq!(options, ClientOptions::default());
+    }
+}
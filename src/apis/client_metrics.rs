@@ -0,0 +1,67 @@
+//! Client side metrics of catalog operations, recorded with the `metrics` crate.
+//!
+//! Install any `metrics` recorder, for example a Prometheus exporter, to collect them.
+//! All metrics are labeled with the `operation`, like `load_table` or `get_token`.
+//!
+//! There is no metric of retries, the client doesn't retry requests. Every attempt of a caller
+//! that retries is counted as a request of its own.
+
+use std::time::Duration;
+
+use super::{interceptor::ApiResponse, Error, Operation};
+
+/// Counter of sent requests, labeled with `operation` and `status`.
+pub const REQUESTS: &str = "iceberg_rest_client_requests_total";
+/// Counter of failed requests, labeled with `operation` and `status`. The status is `transport` if no response was received.
+pub const ERRORS: &str = "iceberg_rest_client_errors_total";
+/// Histogram of the request latency in seconds, labeled with `operation`.
+pub const LATENCY: &str = "iceberg_rest_client_request_duration_seconds";
+/// Counter of refreshed session tokens, labeled with `method`: `refresh` or `exchange` if the refresh failed.
+pub const TOKEN_REFRESHES: &str = "iceberg_rest_client_token_refreshes_total";
+/// Counter of cache lookups, labeled with `cache`: `session` or `table`, and `result`: `hit` or `miss`.
+pub const CACHE_LOOKUPS: &str = "iceberg_rest_client_cache_lookups_total";
+/// Counter of commits rejected with a conflict, labeled with `operation`.
+pub const COMMIT_CONFLICTS: &str = "iceberg_rest_client_commit_conflicts_total";
+
+/// Operations that commit changes and fail with 409 on conflicting concurrent commits.
+const COMMIT_OPERATIONS: [&str; 2] = ["update_table", "commit_transaction"];
+
+/// Register descriptions and units of all metrics with the installed recorder.
+pub fn describe_metrics() {
+    metrics::describe_counter!(REQUESTS, "Requests sent to the catalog.");
+    metrics::describe_counter!(ERRORS, "Catalog requests that failed.");
+    metrics::describe_histogram!(LATENCY, metrics::Unit::Seconds, "Latency of catalog requests.");
+    metrics::describe_counter!(TOKEN_REFRESHES, "Refreshed session tokens.");
+    metrics::describe_counter!(CACHE_LOOKUPS, "Cache lookups of the catalog client.");
+    metrics::describe_counter!(COMMIT_CONFLICTS, "Commits rejected because of a conflict.");
+}
+
+pub(crate) fn record_request<T>(
+    operation: &Operation<'_>,
+    response: &Result<ApiResponse, Error<T>>,
+    latency: Duration,
+) {
+    let status = match response {
+        Ok(response) => response.status.as_u16().to_string(),
+        Err(_) => "transport".to_owned(),
+    };
+    let failed = !matches!(response, Ok(response) if response.is_success());
+    metrics::counter!(REQUESTS, 1, "operation" => operation.name, "status" => status.clone());
+    metrics::histogram!(LATENCY, latency.as_secs_f64(), "operation" => operation.name);
+    if failed {
+        metrics::counter!(ERRORS, 1, "operation" => operation.name, "status" => status);
+    }
+    if let Ok(response) = response {
+        if response.status == reqwest::StatusCode::CONFLICT && COMMIT_OPERATIONS.contains(&operation.name) {
+            metrics::counter!(COMMIT_CONFLICTS, 1, "operation" => operation.name);
+        }
+    }
+}
+
+pub(crate) fn record_token_refresh(method: &'static str) {
+    metrics::counter!(TOKEN_REFRESHES, 1, "method" => method);
+}
+
+pub(crate) fn record_cache_lookup(cache: &'static str, hit: bool) {
+    metrics::counter!(CACHE_LOOKUPS, 1, "cache" => cache, "result" => if hit { "hit" } else { "miss" });
+}
@@ -9,8 +9,16 @@
  */
 
 
+use std::{collections::HashMap, time::Duration};
+
 use reqwest;
 
+use super::client::{ClientOptions, DEFAULT_CONNECTION_TIMEOUT, DEFAULT_REQUEST_TIMEOUT};
+use super::interceptor::{BoxError, InterceptorChain};
+use crate::secret::Secret;
+#[cfg(feature = "sigv4")]
+use super::sigv4::SigV4Signer;
+
 
 #[derive(Debug, Clone)]
 pub struct Configuration {
@@ -18,18 +26,30 @@ pub struct Configuration {
     pub user_agent: Option<String>,
     pub client: reqwest::Client,
     pub basic_auth: Option<BasicAuth>,
-    pub oauth_access_token: Option<String>,
-    pub bearer_access_token: Option<String>,
+    pub oauth_access_token: Option<Secret>,
+    pub bearer_access_token: Option<Secret>,
     pub api_key: Option<ApiKey>,
+    /// Request timeouts for single operations, like `update_table`, that replace the timeout of the client.
+    pub operation_timeouts: HashMap<String, Duration>,
+    /// Interceptors that are applied to every request and response.
+    pub interceptors: InterceptorChain,
+    /// Sign every request with AWS SigV4. Signing happens after all interceptors have run.
+    #[cfg(feature = "sigv4")]
+    pub sigv4_signer: Option<SigV4Signer>,
     // TODO: take an oauth2 token source, similar to the go one
 }
 
-pub type BasicAuth = (String, Option<String>);
+pub type BasicAuth = (String, Option<Secret>);
+
+/// Header the api key is sent in, if [`ApiKey::header`] is not set.
+pub const DEFAULT_API_KEY_HEADER: &str = "X-API-Key";
 
 #[derive(Debug, Clone)]
 pub struct ApiKey {
     pub prefix: Option<String>,
-    pub key: String,
+    pub key: Secret,
+    /// Name of the header the key is sent in. Defaults to [`DEFAULT_API_KEY_HEADER`].
+    pub header: Option<String>,
 }
 
 
@@ -37,6 +57,53 @@ impl Configuration {
     pub fn new() -> Configuration {
         Configuration::default()
     }
+
+    /// Replace the http client by one built from the options and take over their operation timeouts.
+    pub fn with_client_options(mut self, options: &ClientOptions) -> Result<Configuration, BoxError> {
+        let (client, base_path) = options.build_client(&self.base_path)?;
+        self.client = client;
+        self.base_path = base_path;
+        self.operation_timeouts = options.operation_timeouts.clone();
+        Ok(self)
+    }
+
+    /// Apply all configured authentication schemes to a request.
+    ///
+    /// The `Authorization` header is set from at most one of `oauth_access_token`, `bearer_access_token`
+    /// and `basic_auth`, in this order of precedence. Configuring more than one of them with different
+    /// credentials is an error. The api key is sent in its own header and is combined with any of them.
+    pub fn apply_auth(&self, mut req_builder: reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder, String> {
+        let token = match (&self.oauth_access_token, &self.bearer_access_token) {
+            (Some(oauth), Some(bearer)) if oauth != bearer => {
+                return Err("Both oauth_access_token and bearer_access_token are set with different tokens.".to_owned())
+            }
+            (Some(token), _) | (None, Some(token)) => Some(token),
+            (None, None) => None,
+        };
+        match (token, &self.basic_auth) {
+            (Some(_), Some(_)) => {
+                return Err("basic_auth can't be combined with a bearer or oauth access token.".to_owned())
+            }
+            (Some(token), None) => {
+                req_builder = req_builder.bearer_auth(token.expose());
+            }
+            (None, Some((username, password))) => {
+                req_builder = req_builder.basic_auth(username, password.as_ref().map(Secret::expose));
+            }
+            (None, None) => (),
+        }
+        if let Some(ref api_key) = self.api_key {
+            let value = match api_key.prefix {
+                Some(ref prefix) => format!("{} {}", prefix, api_key.key.expose()),
+                None => api_key.key.expose().to_owned(),
+            };
+            let header = api_key.header.as_deref().unwrap_or(DEFAULT_API_KEY_HEADER);
+            let mut value = reqwest::header::HeaderValue::from_str(&value).map_err(|err| err.to_string())?;
+            value.set_sensitive(true);
+            req_builder = req_builder.header(header, value);
+        }
+        Ok(req_builder)
+    }
 }
 
 impl Default for Configuration {
@@ -44,12 +111,49 @@ impl Default for Configuration {
         Configuration {
             base_path: "https://localhost".to_owned(),
             user_agent: Some("OpenAPI-Generator/0.0.1/rust".to_owned()),
-            client: reqwest::Client::new(),
+            client: reqwest::Client::builder()
+                .connect_timeout(DEFAULT_CONNECTION_TIMEOUT)
+                .timeout(DEFAULT_REQUEST_TIMEOUT)
+                .build()
+                .expect("Default http client can't be built"),
             basic_auth: None,
             oauth_access_token: None,
             bearer_access_token: None,
             api_key: None,
-
+            operation_timeouts: HashMap::new(),
+            interceptors: InterceptorChain::default(),
+            #[cfg(feature = "sigv4")]
+            sigv4_signer: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiKey, Configuration};
+
+    #[test]
+    fn test_apply_auth() {
+        let mut configuration = Configuration {
+            bearer_access_token: Some("token".into()),
+            api_key: Some(ApiKey {
+                prefix: None,
+                key: "key".into(),
+                header: None,
+            }),
+            ..Default::default()
+        };
+        let request = configuration
+            .apply_auth(reqwest::Client::new().get("http://localhost:8080/v1/config"))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.headers().get("authorization").unwrap(), "Bearer token");
+        assert_eq!(request.headers().get("x-api-key").unwrap(), "key");
+
+        configuration.basic_auth = Some(("user".to_owned(), None));
+        configuration
+            .apply_auth(reqwest::Client::new().get("http://localhost:8080/v1/config"))
+            .expect_err("Conflicting authentication schemes");
+    }
+}
@@ -0,0 +1,183 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+
+use super::{Error, ResponseContent};
+
+/// Error returned by an [`Interceptor`] to abort a request.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Response of a catalog call after its body has been read, but before it is parsed.
+#[derive(Debug, Clone)]
+pub struct ApiResponse {
+    pub method: reqwest::Method,
+    pub url: reqwest::Url,
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub content: String,
+    /// Request id reported by the server in the `X-Request-ID` header, or the one sent with the request.
+    pub request_id: Option<String>,
+}
+
+impl ApiResponse {
+    /// Returns true if the status code is neither a client nor a server error.
+    pub fn is_success(&self) -> bool {
+        !self.status.is_client_error() && !self.status.is_server_error()
+    }
+
+    /// Converts an unsuccessful response into the typed error of the operation.
+    pub fn into_error<T: DeserializeOwned>(self) -> Error<T> {
+        let entity: Option<T> = serde_json::from_str(&self.content).ok();
+        Error::ResponseError(ResponseContent {
+            status: self.status,
+            content: self.content,
+            entity,
+            request_id: self.request_id,
+        })
+    }
+}
+
+/// Hook into the request pipeline shared by all functions of the apis layer.
+///
+/// Interceptors can add headers, sign requests, log or rewrite responses.
+/// Request hooks run in the order the interceptors were added, response hooks in reverse order.
+#[async_trait]
+pub trait Interceptor: Send + Sync {
+    /// Called with the fully built request right before it is sent.
+    async fn intercept_request(&self, _request: &mut reqwest::Request) -> Result<(), BoxError> {
+        Ok(())
+    }
+    /// Called with the response before it is handed back to the calling function.
+    async fn intercept_response(&self, _response: &mut ApiResponse) -> Result<(), BoxError> {
+        Ok(())
+    }
+}
+
+/// Ordered list of interceptors that is applied to every request.
+#[derive(Clone, Default)]
+pub struct InterceptorChain {
+    interceptors: Vec<Arc<dyn Interceptor>>,
+}
+
+impl InterceptorChain {
+    pub fn new() -> Self {
+        InterceptorChain::default()
+    }
+
+    /// Append an interceptor to the end of the chain.
+    pub fn with(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Append an already shared interceptor to the end of the chain.
+    pub fn push(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.interceptors.push(interceptor);
+    }
+
+    pub fn len(&self) -> usize {
+        self.interceptors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.interceptors.is_empty()
+    }
+
+    pub(crate) async fn intercept_request(
+        &self,
+        request: &mut reqwest::Request,
+    ) -> Result<(), BoxError> {
+        for interceptor in &self.interceptors {
+            interceptor.intercept_request(request).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn intercept_response(&self, response: &mut ApiResponse) -> Result<(), BoxError> {
+        for interceptor in self.interceptors.iter().rev() {
+            interceptor.intercept_response(response).await?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for InterceptorChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterceptorChain")
+            .field("len", &self.interceptors.len())
+            .finish()
+    }
+}
+
+/// Interceptor that adds a fixed set of headers to every request, for example
+/// `X-Iceberg-Access-Delegation` or a tenant id.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderInterceptor {
+    headers: reqwest::header::HeaderMap,
+}
+
+impl HeaderInterceptor {
+    pub fn new(headers: reqwest::header::HeaderMap) -> Self {
+        HeaderInterceptor { headers }
+    }
+}
+
+#[async_trait]
+impl Interceptor for HeaderInterceptor {
+    async fn intercept_request(&self, request: &mut reqwest::Request) -> Result<(), BoxError> {
+        for (name, value) in self.headers.iter() {
+            request.headers_mut().insert(name.clone(), value.clone());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    use super::{ApiResponse, HeaderInterceptor, InterceptorChain};
+    use crate::apis::Error;
+
+    #[tokio::test]
+    async fn test_header_interceptor() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-iceberg-access-delegation",
+            HeaderValue::from_static("vended-credentials"),
+        );
+        let chain = InterceptorChain::new().with(HeaderInterceptor::new(headers));
+        let mut request = reqwest::Client::new()
+            .get("http://localhost:8080/v1/config")
+            .build()
+            .unwrap();
+        chain.intercept_request(&mut request).await.unwrap();
+        assert_eq!(
+            request.headers().get("X-Iceberg-Access-Delegation").unwrap(),
+            "vended-credentials"
+        );
+    }
+
+    #[test]
+    fn test_into_error() {
+        let response = ApiResponse {
+            method: reqwest::Method::POST,
+            url: "http://localhost:8080/v1/namespaces".parse().unwrap(),
+            status: reqwest::StatusCode::CONFLICT,
+            headers: HeaderMap::new(),
+            content: r#"{"error":{"message":"Namespace already exists","type":"AlreadyExistsException","code":409}}"#
+                .to_owned(),
+            request_id: Some("7d2a5c1e".to_owned()),
+        };
+        let error = response.into_error::<serde_json::Value>();
+        assert_eq!(
+            error.to_string(),
+            "error in response: status code 409 Conflict (request id 7d2a5c1e)"
+        );
+        match error {
+            Error::ResponseError(content) => assert!(content.entity.is_some()),
+            _ => panic!("Expected a response error"),
+        }
+    }
+}
@@ -6,6 +6,8 @@ pub struct ResponseContent<T> {
     pub status: reqwest::StatusCode,
     pub content: String,
     pub entity: Option<T>,
+    /// Request id of the failed request, to correlate it with the server logs.
+    pub request_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -14,6 +16,9 @@ pub enum Error<T> {
     Serde(serde_json::Error),
     Io(std::io::Error),
     ResponseError(ResponseContent<T>),
+    Interceptor(interceptor::BoxError),
+    Configuration(String),
+    Auth(oauth::AuthError),
 }
 
 impl <T> fmt::Display for Error<T> {
@@ -22,7 +27,13 @@ impl <T> fmt::Display for Error<T> {
             Error::Reqwest(e) => ("reqwest", e.to_string()),
             Error::Serde(e) => ("serde", e.to_string()),
             Error::Io(e) => ("IO", e.to_string()),
-            Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+            Error::ResponseError(e) => match e.request_id {
+                Some(ref request_id) => ("response", format!("status code {} (request id {})", e.status, request_id)),
+                None => ("response", format!("status code {}", e.status)),
+            },
+            Error::Interceptor(e) => ("interceptor", e.to_string()),
+            Error::Configuration(e) => ("configuration", e.to_string()),
+            Error::Auth(e) => ("auth", e.to_string()),
         };
         write!(f, "error in {}: {}", module, e)
     }
@@ -35,6 +46,9 @@ impl <T: fmt::Debug> error::Error for Error<T> {
             Error::Serde(e) => e,
             Error::Io(e) => e,
             Error::ResponseError(_) => return None,
+            Error::Interceptor(e) => &**e,
+            Error::Configuration(_) => return None,
+            Error::Auth(e) => e,
         })
     }
 }
@@ -61,8 +75,210 @@ pub fn urlencode<T: AsRef<str>>(s: T) -> String {
     ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
 }
 
+/// Header with a key that lets the server recognize a repeated mutation.
+pub const IDEMPOTENCY_KEY: &str = "idempotency-key";
+/// Header with the id of a request, sent with every request and read from the responses.
+pub const REQUEST_ID: &str = "x-request-id";
+
+/// The catalog operation a request belongs to. Used to look up operation timeouts and to label traces.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Operation<'a> {
+    /// Name of the calling function, like `load_table`.
+    pub name: &'static str,
+    /// Path template of the endpoint, like `/v1/{prefix}/namespaces/{namespace}/tables/{table}`.
+    pub path: &'static str,
+    pub prefix: Option<&'a str>,
+    pub namespace: Option<&'a str>,
+    pub table: Option<&'a str>,
+    /// Mutations carry an idempotency key.
+    pub mutation: bool,
+}
+
+impl<'a> Operation<'a> {
+    pub fn new(name: &'static str, path: &'static str) -> Self {
+        Operation { name, path, prefix: None, namespace: None, table: None, mutation: false }
+    }
+
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    pub fn namespace(mut self, namespace: &'a str) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    pub fn table(mut self, table: &'a str) -> Self {
+        self.table = Some(table);
+        self
+    }
+
+    pub fn mutation(mut self) -> Self {
+        self.mutation = true;
+        self
+    }
+}
+
+/// Sends a request through the shared pipeline: default headers, timeouts and authentication from the
+/// configuration are applied, then the configured interceptors, before the response body is read.
+/// With the `tracing` feature every call runs in a span describing the operation, and with the
+/// `opentelemetry` feature the trace context of that span is sent in the `traceparent` and `tracestate` headers.
+/// The `metrics` feature records request counts and latencies, see [`client_metrics`].
+pub(crate) async fn execute<T>(configuration: &configuration::Configuration, operation: Operation<'_>, req_builder: reqwest::RequestBuilder) -> Result<interceptor::ApiResponse, Error<T>> {
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    let start = std::time::Instant::now();
+    #[cfg(feature = "tracing")]
+    let span = telemetry::span(&operation);
+
+    let response = send(configuration, &operation, req_builder);
+    #[cfg(feature = "tracing")]
+    let response = tracing::Instrument::instrument(response, span.clone());
+    let response = response.await;
+
+    #[cfg(feature = "tracing")]
+    telemetry::record(&span, &response, start.elapsed());
+    #[cfg(feature = "metrics")]
+    client_metrics::record_request(&operation, &response, start.elapsed());
+    response
+}
+
+async fn send<T>(configuration: &configuration::Configuration, operation: &Operation<'_>, mut req_builder: reqwest::RequestBuilder) -> Result<interceptor::ApiResponse, Error<T>> {
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(timeout) = configuration.operation_timeouts.get(operation.name) {
+        req_builder = req_builder.timeout(*timeout);
+    }
+    req_builder = configuration.apply_auth(req_builder).map_err(Error::Configuration)?;
+
+    let mut request = req_builder.build()?;
+    // Ids set by the caller are kept. The idempotency key is generated once per call, before anything could resend the request.
+    let headers = request.headers_mut();
+    if !headers.contains_key(REQUEST_ID) {
+        headers.insert(REQUEST_ID, generate_id());
+    }
+    if operation.mutation && !headers.contains_key(IDEMPOTENCY_KEY) {
+        headers.insert(IDEMPOTENCY_KEY, generate_id());
+    }
+    configuration.interceptors.intercept_request(&mut request).await.map_err(Error::Interceptor)?;
+    #[cfg(feature = "opentelemetry")]
+    propagation::inject_trace_context(&mut request);
+    #[cfg(feature = "sigv4")]
+    if let Some(ref signer) = configuration.sigv4_signer {
+        signer.sign(&mut request).map_err(Error::Interceptor)?;
+    }
+    #[cfg(feature = "tracing")]
+    telemetry::log_request(&request);
+
+    let method = request.method().clone();
+    let url = request.url().clone();
+    let sent_request_id = request.headers().get(REQUEST_ID).cloned();
+    let response = configuration.client.execute(request).await?;
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let content = response.text().await?;
+    let request_id = headers
+        .get(REQUEST_ID)
+        .or(sent_request_id.as_ref())
+        .and_then(|x| x.to_str().ok())
+        .map(ToOwned::to_owned);
+
+    let mut response = interceptor::ApiResponse { method, url, status, headers, content, request_id };
+    configuration.interceptors.intercept_response(&mut response).await.map_err(Error::Interceptor)?;
+    #[cfg(feature = "tracing")]
+    telemetry::log_response(&response);
+    Ok(response)
+}
+
+fn generate_id() -> reqwest::header::HeaderValue {
+    reqwest::header::HeaderValue::from_str(&uuid::Uuid::new_v4().to_string()).expect("Uuid is a valid header value")
+}
+
 pub mod catalog_api_api;
+pub mod client;
+#[cfg(feature = "metrics")]
+pub mod client_metrics;
 pub mod configuration_api_api;
 pub mod o_auth2_api_api;
 
 pub mod configuration;
+pub mod interceptor;
+pub mod oauth;
+#[cfg(feature = "opentelemetry")]
+mod propagation;
+pub mod proxy;
+#[cfg(feature = "sigv4")]
+pub mod sigv4;
+#[cfg(feature = "tracing")]
+pub mod telemetry;
+pub mod tls;
+
+#[cfg(test)]
+mod tests {
+    use super::{catalog_api_api, configuration::Configuration, Error, IDEMPOTENCY_KEY, REQUEST_ID};
+    use crate::{
+        models::CreateNamespaceRequest,
+        test_util::{json_response, ServerStub},
+    };
+
+    #[tokio::test]
+    async fn test_request_headers() {
+        let server = ServerStub::start(vec![
+            json_response("200 OK", r#"{"namespace":["sales"]}"#),
+            json_response("200 OK", r#"{"namespaces":[["sales"]]}"#),
+            json_response("404 Not Found", r#"{"error":{"message":"Namespace does not exist","type":"NoSuchNamespaceException","code":404}}"#),
+        ])
+        .await;
+        let configuration = Configuration {
+            base_path: server.uri.clone(),
+            ..Default::default()
+        };
+        catalog_api_api::create_namespace(&configuration, "my_catalog", Some(CreateNamespaceRequest::new(vec!["sales".to_owned()])))
+            .await
+            .unwrap();
+        catalog_api_api::list_namespaces(&configuration, "my_catalog", None, None, None).await.unwrap();
+        let error = catalog_api_api::load_namespace_metadata(&configuration, "my_catalog", "marketing")
+            .await
+            .expect_err("Namespace doesn't exist");
+
+        let requests = server.requests().await;
+        assert_eq!(requests[0].method, "POST");
+        assert!(requests[0].header(IDEMPOTENCY_KEY).is_some());
+        assert_eq!(requests[1].method, "GET");
+        assert!(requests[1].header(IDEMPOTENCY_KEY).is_none());
+        assert!(requests[2].header(IDEMPOTENCY_KEY).is_none());
+
+        let request_ids: Vec<_> = requests.iter().map(|x| x.header(REQUEST_ID).unwrap()).collect();
+        assert_ne!(request_ids[0], request_ids[1]);
+        assert_ne!(request_ids[1], request_ids[2]);
+        // The id of the failed request is reported if the server doesn't echo it
+        match error {
+            Error::ResponseError(content) => assert_eq!(content.request_id.as_deref(), Some(request_ids[2])),
+            _ => panic!("Expected a response error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_headers_set_by_caller() {
+        let server = ServerStub::start(vec![json_response("200 OK", r#"{"namespace":["sales"]}"#)]).await;
+        let configuration = Configuration {
+            base_path: server.uri.clone(),
+            ..Default::default()
+        };
+        let req_builder = configuration
+            .client
+            .post(format!("{}/v1/my_catalog/namespaces", configuration.base_path))
+            .header(REQUEST_ID, "request-1")
+            .header(IDEMPOTENCY_KEY, "key-1")
+            .json(&CreateNamespaceRequest::new(vec!["sales".to_owned()]));
+        super::execute::<()>(&configuration, super::Operation::new("create_namespace", "/v1/{prefix}/namespaces").mutation(), req_builder)
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(requests[0].header(REQUEST_ID), Some("request-1"));
+        assert_eq!(requests[0].header(IDEMPOTENCY_KEY), Some("key-1"));
+    }
+}
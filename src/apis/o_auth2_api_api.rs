@@ -11,7 +11,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 
@@ -35,15 +34,6 @@ pub async fn get_token(configuration: &configuration::Configuration, grant_type:
     let local_var_uri_str = format!("{}/v1/oauth/tokens", local_var_configuration.base_path);
     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
-    if let Some(ref local_var_token) = local_var_configuration.oauth_access_token {
-        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
-    };
     let mut local_var_form_params = std::collections::HashMap::new();
     if let Some(local_var_param_value) = grant_type {
         local_var_form_params.insert("grant_type", local_var_param_value.to_string());
@@ -74,18 +64,17 @@ pub async fn get_token(configuration: &configuration::Configuration, grant_type:
     }
     local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    let local_var_resp = super::execute(local_var_configuration, super::Operation::new("get_token", "/v1/oauth/tokens"), local_var_req_builder).await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+    if local_var_resp.is_success() {
+        let local_var_token: crate::models::GetToken200Response = serde_json::from_str(&local_var_resp.content)?;
+        super::oauth::validate_token(&local_var_token, requested_token_type, subject_token.is_some()).map_err(Error::Auth)?;
+        Ok(local_var_token)
     } else {
-        let local_var_entity: Option<GetTokenError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
+        match serde_json::from_str::<crate::models::GetToken400Response>(&local_var_resp.content) {
+            Ok(local_var_entity) => Err(Error::Auth(super::oauth::AuthError::rejected(local_var_resp.status, local_var_entity))),
+            Err(_) => Err(local_var_resp.into_error()),
+        }
     }
 }
 
@@ -0,0 +1,172 @@
+use std::fmt;
+
+use crate::models::{self, get_token_200_response, get_token_400_response};
+
+/// Tokens that are valid for longer than a year are rejected, such a lifetime is most likely an error of the server.
+const MAX_EXPIRES_IN: i32 = 365 * 24 * 60 * 60;
+
+/// Failure to obtain a usable token from the OAuth2 token endpoint.
+#[derive(Debug, Clone)]
+pub enum AuthError {
+    /// The token endpoint rejected the request, for example with `invalid_client` or `invalid_scope`.
+    /// Unknown error codes, like those of extensions, are kept with their description.
+    Rejected {
+        status: reqwest::StatusCode,
+        error: get_token_400_response::Error,
+        description: Option<String>,
+        uri: Option<String>,
+    },
+    /// The token endpoint returned a token that can't be used.
+    InvalidToken(String),
+}
+
+impl AuthError {
+    pub fn rejected(status: reqwest::StatusCode, response: models::GetToken400Response) -> Self {
+        AuthError::Rejected {
+            status,
+            error: response.error,
+            description: response.error_description,
+            uri: response.error_uri,
+        }
+    }
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthError::Rejected {
+                status,
+                error,
+                description,
+                uri,
+            } => {
+                write!(f, "{} (status code {})", error, status)?;
+                if let Some(description) = description {
+                    write!(f, ": {}", description)?;
+                }
+                if let Some(uri) = uri {
+                    write!(f, ", see {}", uri)?;
+                }
+                Ok(())
+            }
+            AuthError::InvalidToken(message) => write!(f, "invalid token response: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+/// Check that a token response contains a bearer token with a sensible lifetime
+/// and, if a token type was requested, that the issued token has this type.
+/// Token exchanges may also issue tokens of type `N_A`, which aren't access tokens.
+pub fn validate_token(
+    response: &models::GetToken200Response,
+    requested_token_type: Option<models::TokenType>,
+    exchange: bool,
+) -> Result<(), AuthError> {
+    match response.token_type {
+        get_token_200_response::TokenType::Bearer => (),
+        get_token_200_response::TokenType::NA if exchange => (),
+        ref token_type => {
+            return Err(AuthError::InvalidToken(format!(
+                "expected token type bearer, got {}",
+                token_type
+            )))
+        }
+    }
+    if let Some(expires_in) = response.expires_in {
+        if expires_in <= 0 || expires_in > MAX_EXPIRES_IN {
+            return Err(AuthError::InvalidToken(format!(
+                "expires_in must be between 1 and {} seconds, got {}",
+                MAX_EXPIRES_IN, expires_in
+            )));
+        }
+    }
+    if let (Some(requested), Some(issued)) = (requested_token_type, response.issued_token_type) {
+        if requested != issued {
+            return Err(AuthError::InvalidToken(format!(
+                "requested token type {}, but {} was issued",
+                requested.to_string(),
+                issued.to_string()
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{self, get_token_200_response, get_token_400_response};
+
+    use super::{validate_token, AuthError};
+
+    #[test]
+    fn test_validate_token() {
+        let mut response = models::GetToken200Response::new(
+            "token".into(),
+            get_token_200_response::TokenType::Bearer,
+        );
+        response.expires_in = Some(3600);
+        response.issued_token_type = Some(models::TokenType::AccessToken);
+        validate_token(&response, Some(models::TokenType::AccessToken), false).unwrap();
+        validate_token(&response, Some(models::TokenType::IdToken), false)
+            .expect_err("Issued token type doesn't match");
+
+        response.expires_in = Some(0);
+        validate_token(&response, None, false).expect_err("Token without lifetime");
+        response.expires_in = Some(i32::MAX);
+        validate_token(&response, None, false).expect_err("Token valid for decades");
+
+        response.expires_in = None;
+        response.token_type = get_token_200_response::TokenType::Mac;
+        validate_token(&response, None, false).expect_err("Not a bearer token");
+    }
+
+    #[test]
+    fn test_validate_token_type() {
+        let parse = |token_type: &str| -> models::GetToken200Response {
+            serde_json::from_str(&format!(
+                r#"{{"access_token":"token","token_type":"{}"}}"#,
+                token_type
+            ))
+            .unwrap()
+        };
+
+        validate_token(&parse("Bearer"), None, false).unwrap();
+        validate_token(&parse("BEARER"), None, false).unwrap();
+
+        let response = parse("N_A");
+        assert_eq!(response.token_type, get_token_200_response::TokenType::NA);
+        validate_token(&response, None, true).unwrap();
+        validate_token(&response, None, false).expect_err("Not an access token");
+
+        let response = parse("pop");
+        assert_eq!(
+            response.token_type,
+            get_token_200_response::TokenType::Other("pop".to_owned())
+        );
+        assert!(matches!(
+            validate_token(&response, None, true),
+            Err(AuthError::InvalidToken(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejected_with_unknown_error() {
+        let response: models::GetToken400Response = serde_json::from_str(
+            r#"{"error":"invalid_target","error_description":"Unknown audience"}"#,
+        )
+        .unwrap();
+        assert_eq!(response.error, get_token_400_response::Error::InvalidTarget);
+
+        let response: models::GetToken400Response = serde_json::from_str(
+            r#"{"error":"temporarily_unavailable","error_description":"Try again later"}"#,
+        )
+        .unwrap();
+        let error = AuthError::rejected(reqwest::StatusCode::SERVICE_UNAVAILABLE, response);
+        assert_eq!(
+            error.to_string(),
+            "temporarily_unavailable (status code 503 Service Unavailable): Try again later"
+        );
+    }
+}
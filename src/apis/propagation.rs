@@ -0,0 +1,75 @@
+//! W3C trace context propagation to the catalog server.
+//!
+//! The `traceparent` and `tracestate` headers are taken from the OpenTelemetry context of the current
+//! tracing span, which is the span of the catalog request. If the span has no OpenTelemetry context,
+//! for example because no `tracing-opentelemetry` layer is installed, the current OpenTelemetry context is used.
+
+use opentelemetry::{
+    propagation::{Injector, TextMapPropagator},
+    sdk::propagation::TraceContextPropagator,
+    trace::TraceContextExt,
+    Context,
+};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl<'a> Injector for HeaderInjector<'a> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+/// Add the trace context of the current span to the request headers.
+pub(crate) fn inject_trace_context(request: &mut reqwest::Request) {
+    let context = tracing::Span::current().context();
+    let context = if context.span().span_context().is_valid() {
+        context
+    } else {
+        Context::current()
+    };
+    inject_context(&context, request.headers_mut());
+}
+
+fn inject_context(context: &Context, headers: &mut HeaderMap) {
+    TraceContextPropagator::new().inject_context(context, &mut HeaderInjector(headers));
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::{
+        trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+        Context,
+    };
+    use reqwest::header::HeaderMap;
+
+    use super::inject_context;
+
+    #[test]
+    fn test_inject_context() {
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let context = Context::new().with_remote_span_context(span_context);
+        let mut headers = HeaderMap::new();
+        inject_context(&context, &mut headers);
+        assert_eq!(
+            headers.get("traceparent").unwrap(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+
+        let mut headers = HeaderMap::new();
+        inject_context(&Context::new(), &mut headers);
+        assert!(headers.get("traceparent").is_none());
+    }
+}
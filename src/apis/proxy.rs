@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use super::interceptor::BoxError;
+use crate::secret::Secret;
+
+/// Catalog property for the uri of the proxy all catalog requests are sent through.
+pub const PROXY_URI: &str = "rest.proxy.uri";
+/// Catalog property for the user name of the proxy basic authentication.
+pub const PROXY_USERNAME: &str = "rest.proxy.username";
+/// Catalog property for the password of the proxy basic authentication.
+pub const PROXY_PASSWORD: &str = "rest.proxy.password";
+/// Catalog property for a comma separated list of hosts, domains and ip ranges that are not proxied.
+pub const NO_PROXY: &str = "rest.proxy.no-proxy";
+/// Catalog property to use the proxies from the `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` environment variables.
+pub const PROXY_FROM_ENV: &str = "rest.proxy.from-env";
+
+/// Proxy settings of the http client.
+///
+/// Without a proxy uri, requests are sent directly unless proxies from the environment are enabled.
+/// A configured proxy uri takes precedence over the environment.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProxyOptions {
+    pub uri: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<Secret>,
+    /// Comma separated list of hosts, domains and ip ranges that bypass the proxy, like `NO_PROXY`.
+    pub no_proxy: Option<String>,
+    pub from_env: bool,
+}
+
+impl ProxyOptions {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Self {
+        ProxyOptions {
+            uri: properties.get(PROXY_URI).cloned(),
+            username: properties.get(PROXY_USERNAME).cloned(),
+            password: properties.get(PROXY_PASSWORD).map(|x| x.as_str().into()),
+            no_proxy: properties.get(NO_PROXY).cloned(),
+            from_env: properties
+                .get(PROXY_FROM_ENV)
+                .map(|x| x.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+        }
+    }
+
+    /// Configure the proxies of the client builder.
+    pub fn apply(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, BoxError> {
+        if !self.from_env {
+            builder = builder.no_proxy();
+        }
+        if let Some(ref uri) = self.uri {
+            let mut proxy = reqwest::Proxy::all(uri.as_str())
+                .map_err(|err| format!("Invalid proxy uri {}: {}", uri, err))?;
+            if let Some(ref username) = self.username {
+                proxy = proxy.basic_auth(
+                    username,
+                    self.password.as_ref().map(Secret::expose).unwrap_or_default(),
+                );
+            }
+            if let Some(ref no_proxy) = self.no_proxy {
+                proxy = proxy.no_proxy(reqwest::NoProxy::from_string(no_proxy));
+            }
+            builder = builder.proxy(proxy);
+        }
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ProxyOptions;
+    use crate::test_util::{empty_response, ServerStub};
+
+    #[tokio::test]
+    async fn test_connect_proxy() {
+        let proxy = ServerStub::start(vec![empty_response("403 Forbidden")]).await;
+        let properties = HashMap::from([
+            ("rest.proxy.uri".to_owned(), proxy.uri.clone()),
+            ("rest.proxy.username".to_owned(), "user".to_owned()),
+            ("rest.proxy.password".to_owned(), "password".to_owned()),
+        ]);
+        let client = ProxyOptions::from_properties(&properties)
+            .apply(reqwest::Client::builder())
+            .unwrap()
+            .build()
+            .unwrap();
+        client
+            .get("https://catalog.example.com/v1/config")
+            .send()
+            .await
+            .expect_err("Tunnel rejected by proxy");
+
+        let requests = proxy.requests().await;
+        assert_eq!(requests[0].method, "CONNECT");
+        assert_eq!(requests[0].target, "catalog.example.com:443");
+        // base64 of user:password
+        assert_eq!(
+            requests[0].header("proxy-authorization"),
+            Some("Basic dXNlcjpwYXNzd29yZA==")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_proxy() {
+        let proxy = ServerStub::start(vec![empty_response("403 Forbidden")]).await;
+        let catalog = ServerStub::start(vec![empty_response("200 OK")]).await;
+        let properties = HashMap::from([
+            ("rest.proxy.uri".to_owned(), proxy.uri.clone()),
+            ("rest.proxy.no-proxy".to_owned(), "127.0.0.1".to_owned()),
+        ]);
+        let client = ProxyOptions::from_properties(&properties)
+            .apply(reqwest::Client::builder())
+            .unwrap()
+            .build()
+            .unwrap();
+        let response = client
+            .get(format!("{}/v1/config", catalog.uri))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let requests = catalog.requests().await;
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].target, "/v1/config");
+    }
+}
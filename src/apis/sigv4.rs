@@ -0,0 +1,405 @@
+//! AWS Signature Version 4 signing for REST catalogs behind API Gateway or other AWS endpoints.
+//!
+//! See https://docs.aws.amazon.com/general/latest/gr/sigv4_signing.html
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION};
+use sha2::{Digest, Sha256};
+
+use super::interceptor::BoxError;
+use crate::secret::Secret;
+
+/// Catalog property to enable SigV4 signing.
+pub const SIGV4_ENABLED: &str = "rest.sigv4-enabled";
+/// Catalog property for the region used in the signature.
+pub const SIGNING_REGION: &str = "rest.signing-region";
+/// Catalog property for the service name used in the signature.
+pub const SIGNING_NAME: &str = "rest.signing-name";
+/// Catalog property for the access key id.
+pub const ACCESS_KEY_ID: &str = "rest.access-key-id";
+/// Catalog property for the secret access key.
+pub const SECRET_ACCESS_KEY: &str = "rest.secret-access-key";
+/// Catalog property for the session token of temporary credentials.
+pub const SESSION_TOKEN: &str = "rest.session-token";
+
+const DEFAULT_SIGNING_NAME: &str = "execute-api";
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+const X_AMZ_DATE: &str = "x-amz-date";
+const X_AMZ_SECURITY_TOKEN: &str = "x-amz-security-token";
+/// Header an existing `Authorization` header is moved to, so that it is not lost when signing.
+const ORIGINAL_AUTHORIZATION: &str = "original-authorization";
+
+/// Static AWS credentials.
+#[derive(Debug, Clone)]
+pub struct AwsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: Secret,
+    pub session_token: Option<Secret>,
+}
+
+impl AwsCredentials {
+    pub fn new(access_key_id: String, secret_access_key: Secret) -> Self {
+        AwsCredentials {
+            access_key_id,
+            secret_access_key,
+            session_token: None,
+        }
+    }
+
+    /// Read credentials from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`.
+    pub fn from_env() -> Option<Self> {
+        Some(AwsCredentials {
+            access_key_id: std::env::var("AWS_ACCESS_KEY_ID").ok()?,
+            secret_access_key: std::env::var("AWS_SECRET_ACCESS_KEY").ok()?.into(),
+            session_token: std::env::var("AWS_SESSION_TOKEN").ok().map(Secret::from),
+        })
+    }
+}
+
+/// Signs requests with AWS Signature Version 4.
+#[derive(Debug, Clone)]
+pub struct SigV4Signer {
+    pub credentials: AwsCredentials,
+    pub region: String,
+    pub service: String,
+}
+
+impl SigV4Signer {
+    pub fn new(credentials: AwsCredentials, region: String) -> Self {
+        SigV4Signer {
+            credentials,
+            region,
+            service: DEFAULT_SIGNING_NAME.to_owned(),
+        }
+    }
+
+    /// Create a signer from catalog properties. Returns `Ok(None)` if `rest.sigv4-enabled` is not set to true.
+    ///
+    /// Credentials are taken from `rest.access-key-id` and `rest.secret-access-key` and fall back to the environment.
+    /// The region falls back to `AWS_REGION`.
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Option<Self>, BoxError> {
+        let enabled = properties
+            .get(SIGV4_ENABLED)
+            .map(|x| x.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        if !enabled {
+            return Ok(None);
+        }
+        let credentials = match (
+            properties.get(ACCESS_KEY_ID),
+            properties.get(SECRET_ACCESS_KEY),
+        ) {
+            (Some(access_key_id), Some(secret_access_key)) => AwsCredentials {
+                access_key_id: access_key_id.clone(),
+                secret_access_key: secret_access_key.as_str().into(),
+                session_token: properties.get(SESSION_TOKEN).map(|x| x.as_str().into()),
+            },
+            _ => AwsCredentials::from_env().ok_or("No AWS credentials configured for SigV4 signing.")?,
+        };
+        let region = properties
+            .get(SIGNING_REGION)
+            .cloned()
+            .or_else(|| std::env::var("AWS_REGION").ok())
+            .ok_or("No signing region configured for SigV4 signing.")?;
+        let service = properties
+            .get(SIGNING_NAME)
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_SIGNING_NAME.to_owned());
+        Ok(Some(SigV4Signer {
+            credentials,
+            region,
+            service,
+        }))
+    }
+
+    /// Sign the request with the current time.
+    pub fn sign(&self, request: &mut reqwest::Request) -> Result<(), BoxError> {
+        self.sign_at(request, Utc::now())
+    }
+
+    /// Sign the request as if it was sent at the given time.
+    pub fn sign_at(&self, request: &mut reqwest::Request, time: DateTime<Utc>) -> Result<(), BoxError> {
+        let amz_date = time.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = time.format("%Y%m%d").to_string();
+
+        let headers = request.headers_mut();
+        if let Some(authorization) = headers.remove(AUTHORIZATION) {
+            headers.insert(HeaderName::from_static(ORIGINAL_AUTHORIZATION), authorization);
+        }
+        headers.insert(HeaderName::from_static(X_AMZ_DATE), HeaderValue::from_str(&amz_date)?);
+        if let Some(ref session_token) = self.credentials.session_token {
+            headers.insert(
+                HeaderName::from_static(X_AMZ_SECURITY_TOKEN),
+                HeaderValue::from_str(session_token.expose())?,
+            );
+        }
+
+        let (canonical_headers, signed_headers) = canonical_headers(request)?;
+        let payload = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .unwrap_or_default();
+        let canonical_request = [
+            request.method().as_str().to_owned(),
+            canonical_uri(request.url()),
+            canonical_query(request.url()),
+            canonical_headers,
+            signed_headers.clone(),
+            hex::encode(Sha256::digest(payload)),
+        ]
+        .join("\n");
+
+        let scope = format!("{}/{}/{}/aws4_request", date, self.region, self.service);
+        let string_to_sign = [
+            ALGORITHM.to_owned(),
+            amz_date,
+            scope.clone(),
+            hex::encode(Sha256::digest(canonical_request.as_bytes())),
+        ]
+        .join("\n");
+
+        let date_key = hmac_sha256(
+            format!("AWS4{}", self.credentials.secret_access_key.expose()).as_bytes(),
+            date.as_bytes(),
+        );
+        let region_key = hmac_sha256(&date_key, self.region.as_bytes());
+        let service_key = hmac_sha256(&region_key, self.service.as_bytes());
+        let signing_key = hmac_sha256(&service_key, b"aws4_request");
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+        let authorization = format!(
+            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+            ALGORITHM, self.credentials.access_key_id, scope, signed_headers, signature
+        );
+        request
+            .headers_mut()
+            .insert(AUTHORIZATION, HeaderValue::from_str(&authorization)?);
+        Ok(())
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Percent encode everything except unreserved characters, as required by SigV4.
+fn uri_encode(input: &str, encode_slash: bool) -> String {
+    let mut result = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(byte as char)
+            }
+            b'/' if !encode_slash => result.push('/'),
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
+
+/// The path of the url is already percent encoded. Services other than S3 expect it to be encoded a second time.
+fn canonical_uri(url: &reqwest::Url) -> String {
+    match url.path() {
+        "" => "/".to_owned(),
+        path => uri_encode(path, false),
+    }
+}
+
+fn canonical_query(url: &reqwest::Url) -> String {
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| (uri_encode(&key, true), uri_encode(&value, true)))
+        .collect();
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Returns the canonical headers block and the list of signed headers. All headers of the request are signed.
+fn canonical_headers(request: &reqwest::Request) -> Result<(String, String), BoxError> {
+    let url = request.url();
+    let host = match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{}:{}", host, port),
+        (Some(host), None) => host.to_owned(),
+        (None, _) => return Err("Request url has no host.".into()),
+    };
+    let mut headers: Vec<(String, String)> = vec![("host".to_owned(), host)];
+    for name in request.headers().keys() {
+        let values = request
+            .headers()
+            .get_all(name)
+            .iter()
+            .map(|value| {
+                value
+                    .to_str()
+                    .map(|x| x.split_whitespace().collect::<Vec<&str>>().join(" "))
+            })
+            .collect::<Result<Vec<String>, _>>()?;
+        headers.push((name.as_str().to_owned(), values.join(",")));
+    }
+    headers.sort();
+    let canonical_headers = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value))
+        .collect::<String>();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<&str>>()
+        .join(";");
+    Ok((canonical_headers, signed_headers))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use sha2::{Digest, Sha256};
+
+    use super::{hmac_sha256, uri_encode, AwsCredentials, SigV4Signer};
+    use crate::{
+        apis::{catalog_api_api, configuration::Configuration},
+        models::CreateNamespaceRequest,
+        test_util::{empty_response, json_response, ServerStub, StubRequest},
+    };
+
+    const SECRET_ACCESS_KEY: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+
+    /// Recompute the signature of a request as received by the server.
+    fn verify_signature(request: &StubRequest) -> bool {
+        let authorization = match request.header("authorization") {
+            Some(x) => x,
+            None => return false,
+        };
+        let fields: Vec<&str> = authorization
+            .trim_start_matches("AWS4-HMAC-SHA256 ")
+            .split(", ")
+            .collect();
+        let (credential, signed_headers, signature) = match fields.as_slice() {
+            [credential, signed_headers, signature] => (
+                credential.trim_start_matches("Credential="),
+                signed_headers.trim_start_matches("SignedHeaders="),
+                signature.trim_start_matches("Signature="),
+            ),
+            _ => return false,
+        };
+        let scope = credential.split_once('/').unwrap().1;
+        let scope_parts: Vec<&str> = scope.split('/').collect();
+        let (date, region, service) = (scope_parts[0], scope_parts[1], scope_parts[2]);
+
+        let (path, query) = request
+            .target
+            .split_once('?')
+            .unwrap_or((request.target.as_str(), ""));
+        let mut query: Vec<String> = url::form_urlencoded::parse(query.as_bytes())
+            .map(|(key, value)| format!("{}={}", uri_encode(&key, true), uri_encode(&value, true)))
+            .collect();
+        query.sort();
+        let canonical_headers = signed_headers
+            .split(';')
+            .map(|name| format!("{}:{}\n", name, request.header(name).unwrap_or_default()))
+            .collect::<String>();
+        let canonical_request = [
+            request.method.clone(),
+            uri_encode(path, false),
+            query.join("&"),
+            canonical_headers,
+            signed_headers.to_owned(),
+            hex::encode(Sha256::digest(&request.body)),
+        ]
+        .join("\n");
+        let string_to_sign = [
+            "AWS4-HMAC-SHA256",
+            request.header("x-amz-date").unwrap_or_default(),
+            scope,
+            &hex::encode(Sha256::digest(canonical_request.as_bytes())),
+        ]
+        .join("\n");
+        let key = [region, service, "aws4_request"].iter().fold(
+            hmac_sha256(
+                format!("AWS4{}", SECRET_ACCESS_KEY).as_bytes(),
+                date.as_bytes(),
+            ),
+            |key, x| hmac_sha256(&key, x.as_bytes()),
+        );
+        hex::encode(hmac_sha256(&key, string_to_sign.as_bytes())) == signature
+    }
+
+    #[tokio::test]
+    async fn test_signature_verified_by_server() {
+        let server = ServerStub::with_handler(2, |request| {
+            match (verify_signature(request), request.method.as_str()) {
+                (true, "POST") => json_response("200 OK", r#"{"namespace":["sales","eu"]}"#),
+                (true, _) => json_response("200 OK", r#"{"namespaces":[["sales","eu"]]}"#),
+                (false, _) => empty_response("403 Forbidden"),
+            }
+        })
+        .await;
+        let mut credentials =
+            AwsCredentials::new("AKIDEXAMPLE".to_owned(), SECRET_ACCESS_KEY.into());
+        credentials.session_token = Some("session-token".into());
+        let configuration = Configuration {
+            base_path: server.uri.clone(),
+            bearer_access_token: Some("catalog-token".into()),
+            sigv4_signer: Some(SigV4Signer::new(credentials, "eu-west-1".to_owned())),
+            ..Default::default()
+        };
+
+        catalog_api_api::create_namespace(
+            &configuration,
+            "my catalog",
+            Some(CreateNamespaceRequest::new(vec![
+                "sales".to_owned(),
+                "eu".to_owned(),
+            ])),
+        )
+        .await
+        .expect("Signed request with body accepted");
+        catalog_api_api::list_namespaces(&configuration, "my catalog", Some("sales"), None, None)
+            .await
+            .expect("Signed request with query accepted");
+
+        let requests = server.requests().await;
+        assert_eq!(
+            requests[0].header("original-authorization"),
+            Some("Bearer catalog-token")
+        );
+        assert_eq!(
+            requests[1].header("x-amz-security-token"),
+            Some("session-token")
+        );
+    }
+
+    /// "get-vanilla" example of the AWS SigV4 test suite
+    #[test]
+    fn test_sign_get_vanilla() {
+        let signer = SigV4Signer {
+            credentials: AwsCredentials::new(
+                "AKIDEXAMPLE".to_owned(),
+                "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".into(),
+            ),
+            region: "us-east-1".to_owned(),
+            service: "service".to_owned(),
+        };
+        let mut request = reqwest::Client::new()
+            .get("https://example.amazonaws.com/")
+            .build()
+            .unwrap();
+        signer
+            .sign_at(&mut request, Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap())
+            .unwrap();
+        assert_eq!(
+            request.headers().get("authorization").unwrap(),
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Tracing of catalog requests.
+//!
+//! Every request runs in a `catalog_request` span with the operation, method, path template, prefix,
+//! namespace, table, status code and latency. Request and response bodies are logged at debug level,
+//! with the values of credentials and tokens replaced by `***`.
+//!
+//! The spans have no retry count, the client doesn't retry requests. Every attempt of a caller
+//! that retries gets its own span.
+
+use std::time::Duration;
+
+use reqwest::header::CONTENT_TYPE;
+use tracing::{field, Span};
+
+use super::{interceptor::ApiResponse, Error, Operation};
+
+const REDACTED: &str = "***";
+
+pub(crate) fn span(operation: &Operation<'_>) -> Span {
+    tracing::info_span!(
+        "catalog_request",
+        operation = operation.name,
+        http.method = field::Empty,
+        http.route = operation.path,
+        prefix = operation.prefix,
+        namespace = operation.namespace,
+        table = operation.table,
+        http.status_code = field::Empty,
+        latency_ms = field::Empty,
+        error = field::Empty,
+    )
+}
+
+pub(crate) fn record<T>(span: &Span, response: &Result<ApiResponse, Error<T>>, latency: Duration) {
+    span.record("latency_ms", &(latency.as_millis() as u64));
+    match response {
+        Ok(response) => {
+            span.record("http.status_code", &response.status.as_u16());
+        }
+        Err(err) => {
+            span.record("error", &field::display(err));
+        }
+    }
+}
+
+pub(crate) fn log_request(request: &reqwest::Request) {
+    Span::current().record("http.method", &request.method().as_str());
+    if !tracing::enabled!(tracing::Level::DEBUG) {
+        return;
+    }
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|body| {
+            let is_form = request
+                .headers()
+                .get(CONTENT_TYPE)
+                .map(|x| x.as_bytes().starts_with(b"application/x-www-form-urlencoded"))
+                .unwrap_or(false);
+            redact_body(&String::from_utf8_lossy(body), is_form)
+        })
+        .unwrap_or_default();
+    tracing::debug!(method = %request.method(), url = %request.url(), body = %body, "catalog request");
+}
+
+pub(crate) fn log_response(response: &ApiResponse) {
+    if !tracing::enabled!(tracing::Level::DEBUG) {
+        return;
+    }
+    tracing::debug!(
+        status = response.status.as_u16(),
+        request_id = response.request_id.as_deref(),
+        body = %redact_body(&response.content, false),
+        "catalog response"
+    );
+}
+
+/// Replace the values of secret fields in a JSON or form encoded body.
+fn redact_body(body: &str, is_form: bool) -> String {
+    if is_form {
+        return url::form_urlencoded::parse(body.as_bytes())
+            .fold(
+                url::form_urlencoded::Serializer::new(String::new()),
+                |mut serializer, (key, value)| {
+                    if is_secret_key(&key) {
+                        serializer.append_pair(&key, REDACTED);
+                    } else {
+                        serializer.append_pair(&key, &value);
+                    }
+                    serializer
+                },
+            )
+            .finish();
+    }
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => body.to_owned(),
+    }
+}
+
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_secret_key(key) && !value.is_object() && !value.is_array() {
+                    *value = serde_json::Value::String(REDACTED.to_owned());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => (),
+    }
+}
+
+/// Keys of tokens, credentials and passwords, like `access_token`, `client_secret` or `s3.secret-access-key`.
+/// Token type fields are kept.
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    if key.ends_with("_type") || key.ends_with("-type") {
+        return false;
+    }
+    ["token", "secret", "password", "credential", "api-key", "apikey"]
+        .iter()
+        .any(|x| key.contains(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Subscriber,
+    };
+    use tracing_subscriber::{
+        layer::{Context, SubscriberExt},
+        registry::LookupSpan,
+        Layer,
+    };
+
+    use super::redact_body;
+    use crate::{
+        apis::{catalog_api_api, configuration::Configuration},
+        test_util::{json_response, ServerStub},
+    };
+
+    /// Records the fields of `catalog_request` spans.
+    #[derive(Clone, Default)]
+    struct SpanFields(Arc<Mutex<HashMap<String, String>>>);
+
+    impl Visit for SpanFields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(field.name().to_owned(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(field.name().to_owned(), format!("{:?}", value));
+        }
+    }
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanFields {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+            if attrs.metadata().name() == "catalog_request" {
+                attrs.record(&mut self.clone());
+            }
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+            if ctx.metadata(id).map(|x| x.name()) == Some("catalog_request") {
+                values.record(&mut self.clone());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_span_fields() {
+        let fields = SpanFields::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(fields.clone()));
+        let server =
+            ServerStub::start(vec![json_response("200 OK", r#"{"namespace":["sales"]}"#)]).await;
+        let configuration = Configuration {
+            base_path: server.uri.clone(),
+            ..Default::default()
+        };
+        catalog_api_api::load_namespace_metadata(&configuration, "my_catalog", "sales")
+            .await
+            .unwrap();
+        server.requests().await;
+
+        let fields = fields.0.lock().unwrap();
+        assert_eq!(fields["operation"], "load_namespace_metadata");
+        assert_eq!(fields["http.method"], "GET");
+        assert_eq!(fields["http.route"], "/v1/{prefix}/namespaces/{namespace}");
+        assert_eq!(fields["prefix"], "my_catalog");
+        assert_eq!(fields["namespace"], "sales");
+        assert_eq!(fields["http.status_code"], "200");
+        assert!(fields["latency_ms"].parse::<u64>().is_ok());
+        assert!(!fields.contains_key("error"));
+    }
+
+    #[test]
+    fn test_redact_body() {
+        let body = r#"{"metadata-location":"s3://bucket/metadata.json","config":{"s3.access-key-id":"AKID","s3.secret-access-key":"secret"}}"#;
+        let redacted = redact_body(body, false);
+        assert!(redacted.contains(r#""s3.secret-access-key":"***""#));
+        assert!(redacted.contains(r#""s3.access-key-id":"AKID""#));
+
+        let body = "grant_type=client_credentials&client_id=client&client_secret=secret";
+        assert_eq!(
+            redact_body(body, true),
+            "grant_type=client_credentials&client_id=client&client_secret=***"
+        );
+
+        let body = r#"{"access_token":"token","token_type":"bearer"}"#;
+        let redacted = redact_body(body, false);
+        assert!(redacted.contains(r#""access_token":"***""#));
+        assert!(redacted.contains(r#""token_type":"bearer""#));
+    }
+}
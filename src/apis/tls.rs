@@ -0,0 +1,308 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+use super::interceptor::BoxError;
+use crate::secret::Secret;
+
+/// Catalog property for the path of a PEM bundle with additional trusted CA certificates.
+pub const CA_BUNDLE: &str = "rest.tls.ca-bundle";
+/// Catalog property for the path of the client certificate, either PEM or PKCS#12 (`.p12`, `.pfx`).
+pub const CLIENT_CERT: &str = "rest.tls.client-cert";
+/// Catalog property for the path of the PEM private key of the client certificate.
+pub const CLIENT_KEY: &str = "rest.tls.client-key";
+/// Catalog property for the password of a PKCS#12 client certificate.
+pub const CLIENT_CERT_PASSWORD: &str = "rest.tls.client-cert-password";
+/// Catalog property for the host name the server certificate is verified against, if it differs from the host of the uri.
+pub const HOSTNAME_OVERRIDE: &str = "rest.tls.hostname-override";
+
+const PEM_CERTIFICATE_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+
+/// TLS settings for private catalog endpoints.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TlsOptions {
+    pub ca_bundle: Option<PathBuf>,
+    pub client_cert: Option<PathBuf>,
+    pub client_key: Option<PathBuf>,
+    pub client_cert_password: Option<Secret>,
+    pub hostname_override: Option<String>,
+}
+
+impl TlsOptions {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Self {
+        TlsOptions {
+            ca_bundle: properties.get(CA_BUNDLE).map(PathBuf::from),
+            client_cert: properties.get(CLIENT_CERT).map(PathBuf::from),
+            client_key: properties.get(CLIENT_KEY).map(PathBuf::from),
+            client_cert_password: properties
+                .get(CLIENT_CERT_PASSWORD)
+                .map(|x| x.as_str().into()),
+            hostname_override: properties.get(HOSTNAME_OVERRIDE).cloned(),
+        }
+    }
+
+    /// Add the trusted certificates and the client identity to the client builder.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn apply(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, BoxError> {
+        if let Some(ref path) = self.ca_bundle {
+            let bundle = std::fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read CA bundle {}: {}", path.display(), err))?;
+            for certificate in split_pem_certificates(&bundle) {
+                builder = builder.add_root_certificate(reqwest::Certificate::from_pem(certificate.as_bytes())?);
+            }
+        }
+        if let Some(ref path) = self.client_cert {
+            builder = self.identity(builder, path)?;
+        }
+        Ok(builder)
+    }
+
+    #[cfg(not(any(feature = "native-tls", feature = "rustls-tls")))]
+    pub fn apply(
+        &self,
+        builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, BoxError> {
+        if self.ca_bundle.is_some() || self.client_cert.is_some() {
+            return Err("TLS options require the native-tls or rustls-tls feature.".into());
+        }
+        Ok(builder)
+    }
+
+    /// Add the client identity and select the TLS backend it was built for, identities can't be used with
+    /// the other backend if both features are enabled.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn identity(
+        &self,
+        builder: reqwest::ClientBuilder,
+        path: &std::path::Path,
+    ) -> Result<reqwest::ClientBuilder, BoxError> {
+        let cert = std::fs::read(path)
+            .map_err(|err| format!("Failed to read client certificate {}: {}", path.display(), err))?;
+        let is_pkcs12 = path
+            .extension()
+            .and_then(|x| x.to_str())
+            .map(|x| x.eq_ignore_ascii_case("p12") || x.eq_ignore_ascii_case("pfx"))
+            .unwrap_or(false);
+        if is_pkcs12 {
+            #[cfg(feature = "native-tls")]
+            return Ok(builder
+                .use_native_tls()
+                .identity(reqwest::Identity::from_pkcs12_der(
+                    &cert,
+                    self.client_cert_password
+                        .as_ref()
+                        .map(Secret::expose)
+                        .unwrap_or_default(),
+                )?));
+            #[cfg(not(feature = "native-tls"))]
+            return Err("PKCS#12 client certificates require the native-tls feature.".into());
+        }
+        let key = match self.client_key {
+            Some(ref key_path) => std::fs::read(key_path).map_err(|err| {
+                format!("Failed to read client key {}: {}", key_path.display(), err)
+            })?,
+            None => return Err(format!("No client key configured with {}.", CLIENT_KEY).into()),
+        };
+        #[cfg(feature = "rustls-tls")]
+        return Ok(builder
+            .use_rustls_tls()
+            .identity(reqwest::Identity::from_pem(&[cert, key].concat())?));
+        #[cfg(not(feature = "rustls-tls"))]
+        return Ok(builder
+            .use_native_tls()
+            .identity(reqwest::Identity::from_pkcs8_pem(&cert, &key)?));
+    }
+
+    /// Route requests for the overriding host name to the host of `base_path` and return the base path
+    /// with the host replaced, so that the server certificate is verified against the overriding name.
+    pub fn apply_hostname_override(
+        &self,
+        builder: reqwest::ClientBuilder,
+        base_path: &str,
+    ) -> Result<(reqwest::ClientBuilder, String), BoxError> {
+        let hostname = match self.hostname_override {
+            Some(ref hostname) => hostname,
+            None => return Ok((builder, base_path.to_owned())),
+        };
+        let mut url = url::Url::parse(base_path)?;
+        let host = url
+            .host_str()
+            .ok_or("Catalog uri has no host.")?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_owned();
+        url.set_host(Some(hostname))?;
+        let resolver = HostnameOverrideResolver {
+            hostname: hostname.to_owned(),
+            host,
+        };
+        Ok((
+            builder.dns_resolver(Arc::new(resolver)),
+            url.as_str().trim_end_matches('/').to_owned(),
+        ))
+    }
+}
+
+/// Resolves the overriding host name to the addresses of the catalog host when a connection is opened,
+/// all other names as usual.
+struct HostnameOverrideResolver {
+    hostname: String,
+    host: String,
+}
+
+impl Resolve for HostnameOverrideResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = if name.as_str().eq_ignore_ascii_case(&self.hostname) {
+            self.host.clone()
+        } else {
+            name.as_str().to_owned()
+        };
+        Box::pin(async move {
+            // The port is replaced by the port of the request
+            let addrs = tokio::net::lookup_host((host.as_str(), 0)).await?;
+            Ok(Box::new(addrs.collect::<Vec<_>>().into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Split a PEM bundle into single certificates.
+fn split_pem_certificates(bundle: &str) -> Vec<String> {
+    bundle
+        .split(PEM_CERTIFICATE_BEGIN)
+        .skip(1)
+        .map(|x| format!("{}{}", PEM_CERTIFICATE_BEGIN, x))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{split_pem_certificates, TlsOptions};
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    use {
+        crate::test_util::{empty_response, read_request},
+        rcgen::{BasicConstraints, Certificate, CertificateParams, ExtendedKeyUsagePurpose, IsCa},
+        std::sync::Arc,
+        tokio::{io::AsyncWriteExt, net::TcpListener},
+        tokio_rustls::{
+            rustls::{self, server::AllowAnyAuthenticatedClient, RootCertStore},
+            TlsAcceptor,
+        },
+    };
+
+    #[test]
+    fn test_split_pem_certificates() {
+        let bundle = "# root\n-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n\
+                      -----BEGIN CERTIFICATE-----\nMIIC\n-----END CERTIFICATE-----\n";
+        let certificates = split_pem_certificates(bundle);
+        assert_eq!(certificates.len(), 2);
+        assert!(certificates[1].starts_with("-----BEGIN CERTIFICATE-----\nMIIC"));
+    }
+
+    #[test]
+    fn test_hostname_override() {
+        let properties = HashMap::from([(
+            "rest.tls.hostname-override".to_owned(),
+            "catalog.internal".to_owned(),
+        )]);
+        let options = TlsOptions::from_properties(&properties);
+        let (_, base_path) = options
+            .apply_hostname_override(reqwest::Client::builder(), "https://localhost:8443/api")
+            .unwrap();
+        assert_eq!(base_path, "https://catalog.internal:8443/api");
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    #[tokio::test]
+    async fn test_mutual_tls() {
+        let mut ca_params = CertificateParams::new(vec![]);
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca = Certificate::from_params(ca_params).unwrap();
+        let mut server_params = CertificateParams::new(vec!["catalog.internal".to_owned()]);
+        server_params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+        let server = Certificate::from_params(server_params).unwrap();
+        let mut client_params = CertificateParams::new(vec!["client".to_owned()]);
+        client_params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ClientAuth];
+        let client = Certificate::from_params(client_params).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("tls-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join("ca.pem"), ca.serialize_pem().unwrap()).unwrap();
+        std::fs::write(
+            dir.join("client.pem"),
+            client.serialize_pem_with_signer(&ca).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(dir.join("client.key"), client.serialize_private_key_pem()).unwrap();
+
+        let mut roots = RootCertStore::empty();
+        roots
+            .add(&rustls::Certificate(ca.serialize_der().unwrap()))
+            .unwrap();
+        let config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+            .with_single_cert(
+                vec![rustls::Certificate(
+                    server.serialize_der_with_signer(&ca).unwrap(),
+                )],
+                rustls::PrivateKey(server.serialize_private_key_der()),
+            )
+            .unwrap();
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_path = format!("https://{}/api", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = acceptor.accept(stream).await.unwrap();
+            let request = read_request(&mut stream).await.unwrap();
+            stream
+                .write_all(empty_response("200 OK").as_bytes())
+                .await
+                .unwrap();
+            let client_certificates = stream.get_ref().1.peer_certificates().map(<[_]>::len);
+            (request, client_certificates)
+        });
+
+        let properties = HashMap::from([
+            ("rest.tls.ca-bundle".to_owned(), path(&dir, "ca.pem")),
+            ("rest.tls.client-cert".to_owned(), path(&dir, "client.pem")),
+            ("rest.tls.client-key".to_owned(), path(&dir, "client.key")),
+            (
+                "rest.tls.hostname-override".to_owned(),
+                "catalog.internal".to_owned(),
+            ),
+        ]);
+        let options = TlsOptions::from_properties(&properties);
+        let builder = options.apply(reqwest::Client::builder()).unwrap();
+        let (builder, base_path) = options
+            .apply_hostname_override(builder, &base_path)
+            .unwrap();
+        let response = builder
+            .build()
+            .unwrap()
+            .get(format!("{}/v1/config", base_path))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+        let (request, client_certificates) = handle.await.unwrap();
+        assert_eq!(request.target, "/api/v1/config");
+        assert!(request
+            .header("host")
+            .unwrap()
+            .starts_with("catalog.internal:"));
+        assert_eq!(client_certificates, Some(1));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    fn path(dir: &std::path::Path, name: &str) -> String {
+        dir.join(name).to_str().unwrap().to_owned()
+    }
+}
@@ -1,4 +1,11 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, PoisonError, RwLock,
+    },
+    time::Instant,
+};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
@@ -13,42 +20,247 @@ use iceberg_rs::{
     table::Table,
     view::View,
 };
+use reqwest::StatusCode;
 
 use crate::{
-    apis::{catalog_api_api, configuration},
+    apis::{
+        catalog_api_api::{self, LoadTableOptions, SnapshotsMode},
+        configuration, Error,
+    },
     models::{self},
 };
 
+use self::{
+    builder::{merge_properties, RestCatalogBuilder, CREDENTIAL, PREFIX, TOKEN, URI, WAREHOUSE},
+    capabilities::Capabilities,
+    profile::resolve_references,
+    reporter::CatalogMetricsReporter,
+    session::SessionCache,
+    tables::TableCache,
+};
+
+pub mod builder;
+pub mod capabilities;
+pub mod profile;
+pub mod reporter;
+pub mod session;
+pub mod tables;
+pub mod transaction;
+mod updates;
+mod views;
+
 pub struct RestCatalog {
     name: String,
+    prefix: String,
     configuration: configuration::Configuration,
     object_store: Arc<dyn ObjectStore>,
+    properties: RwLock<HashMap<String, String>>,
+    /// Server configuration the properties were merged with.
+    config: models::CatalogConfig,
+    sessions: SessionCache,
+    metrics_reporter: Option<CatalogMetricsReporter>,
+    capabilities: Capabilities,
+    head_rejected: Arc<HeadRejected>,
+    tables: TableCache,
+}
+
+/// Existence checks whose HEAD requests were rejected by the server. They are answered with GET requests instead.
+#[derive(Debug, Default)]
+struct HeadRejected {
+    namespaces: AtomicBool,
+    tables: AtomicBool,
+}
+
+/// Servers that don't implement HEAD for an endpoint respond with 405 or 501.
+fn is_head_rejected(status: StatusCode) -> bool {
+    status == StatusCode::METHOD_NOT_ALLOWED || status == StatusCode::NOT_IMPLEMENTED
 }
 
 impl RestCatalog {
+    /// Create a catalog that uses its name as the prefix for all catalog paths.
     pub fn new(
         name: String,
         configuration: configuration::Configuration,
         object_store: Arc<dyn ObjectStore>,
     ) -> Self {
         RestCatalog {
+            prefix: name.clone(),
             name,
             configuration,
             object_store,
+            properties: RwLock::default(),
+            config: models::CatalogConfig::default(),
+            sessions: SessionCache::default(),
+            metrics_reporter: None,
+            capabilities: Capabilities::default(),
+            head_rejected: Arc::default(),
+            tables: TableCache::default(),
+        }
+    }
+
+    /// Start building a catalog with the given name.
+    pub fn builder(name: &str) -> RestCatalogBuilder {
+        RestCatalogBuilder::new(name)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Catalog properties after merging the server configuration with the client properties.
+    pub fn properties(&self) -> HashMap<String, String> {
+        // Properties are replaced as a whole, a panic while holding the lock can't leave them half written
+        self.properties
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Optional features supported by the catalog server.
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
+    /// Check if a namespace exists without loading its properties.
+    /// Falls back to loading the namespace if the server doesn't support HEAD requests for namespaces.
+    pub async fn namespace_exists(&self, namespace: &Namespace) -> Result<bool> {
+        let namespace = namespace.to_string();
+        if self.capabilities.namespace_exists() != Some(false)
+            && !self.head_rejected.namespaces.load(Ordering::Relaxed)
+        {
+            match catalog_api_api::namespace_exists(&self.configuration, &self.prefix, &namespace)
+                .await
+            {
+                Ok(()) => return Ok(true),
+                Err(Error::ResponseError(content)) if content.status == StatusCode::NOT_FOUND => {
+                    return Ok(false)
+                }
+                Err(Error::ResponseError(content)) if is_head_rejected(content.status) => {
+                    self.head_rejected.namespaces.store(true, Ordering::Relaxed);
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        match catalog_api_api::load_namespace_metadata(
+            &self.configuration,
+            &self.prefix,
+            &namespace,
+        )
+        .await
+        {
+            Ok(_) => Ok(true),
+            Err(Error::ResponseError(content)) if content.status == StatusCode::NOT_FOUND => {
+                Ok(false)
+            }
+            Err(err) => Err(err.into()),
         }
     }
+
+    async fn read_metadata_file(&self, path: &Path) -> Result<Vec<u8>> {
+        let bytes = self
+            .object_store
+            .get(path)
+            .await
+            .map_err(|err| anyhow!(err.to_string()))?
+            .bytes()
+            .await
+            .map_err(|err| anyhow!(err.to_string()))?;
+        Ok(bytes.to_vec())
+    }
+
+    /// Token of the page after a listing page, `None` after the last page. Listings start with an empty token.
+    fn next_page_token(&self, next_page_token: Option<String>) -> Option<String> {
+        let token = next_page_token.filter(|token| !token.is_empty())?;
+        self.capabilities.record_pagination();
+        Some(token)
+    }
+
+    /// Views live under their own endpoints, table requests for a view fail with 404.
+    fn may_be_view<T>(&self, err: &Error<T>) -> bool {
+        matches!(err, Error::ResponseError(content) if content.status == StatusCode::NOT_FOUND)
+            && self.capabilities.views() != Some(false)
+    }
+
+    /// Register a metadata file as a table through the register endpoint. Falls back to creating the table at the
+    /// metadata file location if the server doesn't advertise the endpoint or rejects it with 404 or 405.
+    async fn register_metadata_file(
+        &self,
+        identifier: &Identifier,
+        metadata_file_location: &str,
+    ) -> Result<()> {
+        let namespace = identifier.namespace().to_string();
+        if self.capabilities.register_table() != Some(false) {
+            match catalog_api_api::register_table(
+                &self.configuration,
+                &self.prefix,
+                &namespace,
+                models::RegisterTableRequest::new(
+                    identifier.name().to_owned(),
+                    metadata_file_location.to_owned(),
+                ),
+            )
+            .await
+            {
+                Ok(_) => return Ok(()),
+                Err(Error::ResponseError(content))
+                    if content.status == StatusCode::NOT_FOUND
+                        || content.status == StatusCode::METHOD_NOT_ALLOWED => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        let mut request = models::CreateTableRequest::new(
+            identifier.name().to_owned(),
+            models::Schema::default(),
+        );
+        request.location = Some(metadata_file_location.to_owned());
+        catalog_api_api::create_table(&self.configuration, &self.prefix, &namespace, Some(request))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Identifier of a table in the requests of the catalog api.
+fn table_identifier(identifier: &Identifier) -> models::TableIdentifier {
+    models::TableIdentifier::new(
+        identifier
+            .namespace()
+            .to_string()
+            .split('.')
+            .map(ToOwned::to_owned)
+            .collect(),
+        identifier.name().to_owned(),
+    )
+}
+
+/// Path of a metadata file in the object store, given its url or path.
+fn metadata_path(location: &str) -> Result<Path> {
+    match url::Url::parse(location) {
+        Ok(url) => Ok(url.path().into()),
+        Err(url::ParseError::RelativeUrlWithoutBase) => Ok(location.into()),
+        Err(err) => Err(anyhow::Error::msg(err)),
+    }
 }
 
 #[async_trait]
 impl Catalog for RestCatalog {
-    /// Lists all tables in the given namespace.
+    /// Lists all tables in the given namespace, following the pages of paginated listings.
     async fn list_tables(&self, namespace: &Namespace) -> Result<Vec<Identifier>> {
-        let tables =
-            catalog_api_api::list_tables(&self.configuration, &self.name, &namespace.to_string())
-                .await?;
-        tables
-            .identifiers
-            .ok_or_else(|| anyhow!("No tables found"))?
+        let namespace = namespace.to_string();
+        let mut identifiers = Vec::new();
+        let mut page_token = Some(String::new());
+        while let Some(token) = page_token {
+            let page = catalog_api_api::list_tables(
+                &self.configuration,
+                &self.prefix,
+                &namespace,
+                Some(&token),
+                None,
+            )
+            .await?;
+            identifiers.extend(page.identifiers.ok_or_else(|| anyhow!("No tables found"))?);
+            page_token = self.next_page_token(page.next_page_token);
+        }
+        identifiers
             .into_iter()
             .map(|x| {
                 let mut vec = x.namespace;
@@ -57,67 +269,101 @@ impl Catalog for RestCatalog {
             })
             .collect::<Result<Vec<Identifier>>>()
     }
-    /// Lists all namespaces in the catalog.
+    /// Lists all namespaces in the catalog, following the pages of paginated listings.
     async fn list_namespaces(&self, parent: Option<&str>) -> Result<Vec<Namespace>> {
-        let namespaces =
-            catalog_api_api::list_namespaces(&self.configuration, &self.name, parent).await?;
+        let mut namespaces = Vec::new();
+        let mut page_token = Some(String::new());
+        while let Some(token) = page_token {
+            let page = catalog_api_api::list_namespaces(
+                &self.configuration,
+                &self.prefix,
+                parent,
+                Some(&token),
+                None,
+            )
+            .await?;
+            namespaces.extend(page.namespaces.ok_or_else(|| anyhow!("No tables found"))?);
+            page_token = self.next_page_token(page.next_page_token);
+        }
         namespaces
-            .namespaces
-            .ok_or_else(|| anyhow!("No tables found"))?
             .into_iter()
             .map(|x| Namespace::try_new(&x))
             .collect::<Result<Vec<Namespace>>>()
     }
     /// Check if a table exists
     async fn table_exists(&self, identifier: &Identifier) -> Result<bool> {
-        catalog_api_api::table_exists(
+        if !self.head_rejected.tables.load(Ordering::Relaxed) {
+            match catalog_api_api::table_exists(
+                &self.configuration,
+                &self.prefix,
+                &identifier.namespace().to_string(),
+                identifier.name(),
+            )
+            .await
+            {
+                Ok(()) => return Ok(true),
+                Err(Error::ResponseError(content)) if content.status == StatusCode::NOT_FOUND => {
+                    return Ok(false)
+                }
+                Err(Error::ResponseError(content)) if is_head_rejected(content.status) => {
+                    self.head_rejected.tables.store(true, Ordering::Relaxed);
+                }
+                Err(err) => return Err(anyhow::Error::msg(err)),
+            }
+        }
+        match catalog_api_api::load_table(
             &self.configuration,
-            &self.name,
+            &self.prefix,
             &identifier.namespace().to_string(),
             identifier.name(),
+            &LoadTableOptions::new().snapshots(SnapshotsMode::Refs),
         )
         .await
-        .map(|_| true)
-        .map_err(anyhow::Error::msg)
+        {
+            Ok(_) => Ok(true),
+            Err(Error::ResponseError(content)) if content.status == StatusCode::NOT_FOUND => {
+                Ok(false)
+            }
+            Err(err) => Err(anyhow::Error::msg(err)),
+        }
     }
     /// Drop a table and delete all data and metadata files.
     async fn drop_table(&self, identifier: &Identifier) -> Result<()> {
-        catalog_api_api::drop_table(
+        match catalog_api_api::drop_table(
             &self.configuration,
-            &self.name,
+            &self.prefix,
             &identifier.namespace().to_string(),
             identifier.name(),
             None,
         )
         .await
-        .map_err(anyhow::Error::msg)
+        {
+            Ok(()) => {
+                self.evict_table(identifier);
+                Ok(())
+            }
+            Err(err) if self.may_be_view(&err) => self.drop_view(identifier).await,
+            Err(err) => Err(anyhow::Error::msg(err)),
+        }
     }
-    /// Load a table.
+    /// Load a table. Falls back to loading a view if there is no table with the identifier.
     async fn load_table(self: Arc<Self>, identifier: &Identifier) -> Result<Relation> {
-        let path: Path = catalog_api_api::load_table(
-            &self.configuration,
-            &self.name,
-            &identifier.namespace().to_string(),
-            identifier.name(),
-        )
-        .await
-        .map(|x| x.metadata_location)?
-        .ok_or(anyhow!("No metadata location provided."))
-        .and_then(|y| url::Url::parse(&y).map_err(anyhow::Error::msg))?
-        .path()
-        .into();
-        let bytes = &self
-            .object_store
-            .get(&path)
+        // The metadata is read from the metadata file, the response only needs to carry its location
+        let metadata_location = match self
+            .load_cached_table(identifier, SnapshotsMode::Refs)
             .await
-            .map_err(|err| anyhow!(err.to_string()))?
-            .bytes()
-            .await
-            .map_err(|err| anyhow!(err.to_string()))?;
-        let metadata: RelationMetadata = serde_json::from_str(
-            std::str::from_utf8(bytes).map_err(|err| anyhow!(err.to_string()))?,
-        )
-        .map_err(|err| anyhow!(err.to_string()))?;
+        {
+            Ok(table) => table
+                .metadata_location()
+                .ok_or(anyhow!("No metadata location provided."))?
+                .to_owned(),
+            Err(err) if self.may_be_view(&err) => {
+                return self.load_view(identifier).await.map(Relation::View);
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let path = metadata_path(&metadata_location)?;
+        let metadata = self.read_relation_metadata(&metadata_location).await?;
         let catalog: Arc<dyn Catalog> = self;
         match metadata {
             RelationMetadata::Table(metadata) => Ok(Relation::Table(
@@ -141,55 +387,105 @@ impl Catalog for RestCatalog {
         }
     }
     /// Invalidate cached table metadata from current catalog.
-    async fn invalidate_table(&self, _identifier: &Identifier) -> Result<()> {
-        unimplemented!()
+    async fn invalidate_table(&self, identifier: &Identifier) -> Result<()> {
+        self.evict_table(identifier);
+        Ok(())
     }
     /// Register a table with the catalog if it doesn't exist.
+    /// Uses the register endpoint unless the server doesn't support it, then the table is created at the metadata file location.
     async fn register_table(
         self: Arc<Self>,
         identifier: Identifier,
         metadata_file_location: &str,
     ) -> Result<Relation> {
-        let mut request = models::CreateTableRequest::new(
-            identifier.name().to_owned(),
-            models::Schema::default(),
-        );
-        request.location = Some(metadata_file_location.to_owned());
-        catalog_api_api::create_table(
-            &self.configuration,
-            &self.name,
-            &identifier.namespace().to_string(),
-            Some(request),
-        )
-        .await?;
+        let bytes = self
+            .read_metadata_file(&metadata_path(metadata_file_location)?)
+            .await?;
+        let metadata: RelationMetadata =
+            serde_json::from_slice(&bytes).map_err(|err| anyhow!(err.to_string()))?;
+        if let RelationMetadata::View(_) = metadata {
+            let metadata: models::ViewMetadata = serde_json::from_slice(&bytes)?;
+            self.register_view(&identifier, &metadata).await?;
+            return self.load_table(&identifier).await;
+        }
+        self.register_metadata_file(&identifier, metadata_file_location)
+            .await?;
         self.load_table(&identifier).await
     }
-    /// Update a table by atomically changing the pointer to the metadata file
+    /// Update a table by committing the changes from the previous to the new metadata file.
+    /// Views are updated by committing the current version of their new metadata file.
     async fn update_table(
         self: Arc<Self>,
         identifier: Identifier,
         metadata_file_location: &str,
-        _previous_metadata_file_location: &str,
+        previous_metadata_file_location: &str,
     ) -> Result<Relation> {
-        let mut update = models::TableUpdate::default();
-        update.location = metadata_file_location.to_owned();
-        let request = models::CommitTableRequest::new(vec![], vec![update]);
-        catalog_api_api::update_table(
+        let bytes = self
+            .read_metadata_file(&metadata_path(metadata_file_location)?)
+            .await?;
+        let metadata: RelationMetadata =
+            serde_json::from_slice(&bytes).map_err(|err| anyhow!(err.to_string()))?;
+        if let RelationMetadata::View(_) = metadata {
+            let metadata: models::ViewMetadata = serde_json::from_slice(&bytes)?;
+            self.commit_view(&identifier, &metadata).await?;
+            return self.load_table(&identifier).await;
+        }
+        let metadata: models::TableMetadata = serde_json::from_slice(&bytes)?;
+        let previous: models::TableMetadata = serde_json::from_slice(
+            &self
+                .read_metadata_file(&metadata_path(previous_metadata_file_location)?)
+                .await?,
+        )?;
+        let request =
+            models::CommitTableRequest::new(vec![], updates::table_updates(&previous, &metadata));
+        let start = Instant::now();
+        let response = catalog_api_api::update_table(
             &self.configuration,
-            &self.name,
+            &self.prefix,
             &identifier.namespace().to_string(),
             identifier.name(),
             Some(request),
         )
         .await?;
+        if let Some(ref metrics_reporter) = self.metrics_reporter {
+            if let Some(report) =
+                reporter::commit_report(&identifier, &response.metadata, start.elapsed())
+            {
+                metrics_reporter.report(&identifier, report).await;
+            }
+        }
         self.load_table(&identifier).await
     }
     /// Initialize a catalog given a custom name and a map of catalog properties.
     /// A custom Catalog implementation must have a no-arg constructor. A compute engine like Spark
     /// or Flink will first initialize the catalog without any arguments, and then call this method to
     /// complete catalog initialization with properties passed into the engine.
-    async fn initialize(self: Arc<Self>, _properties: &HashMap<String, String>) -> Result<()> {
-        unimplemented!()
+    ///
+    /// The properties are merged with the server configuration like the properties of a [`RestCatalogBuilder`].
+    /// Properties that select the server or authenticate the catalog can't change after it was built.
+    async fn initialize(self: Arc<Self>, properties: &HashMap<String, String>) -> Result<()> {
+        let properties = resolve_references(properties.clone())?;
+        let mut current = self
+            .properties
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        for key in [URI, PREFIX, WAREHOUSE, CREDENTIAL, TOKEN] {
+            if matches!(properties.get(key), Some(value) if current.get(key) != Some(value)) {
+                return Err(anyhow!(
+                    "Property {} of catalog {} can't be changed after it was built.",
+                    key,
+                    self.name
+                ));
+            }
+        }
+        let mut client_properties = current.clone();
+        client_properties.extend(properties);
+        *current = merge_properties(
+            &self.config.defaults,
+            &client_properties,
+            &self.config.overrides,
+        );
+        Ok(())
     }
     /// Return the associated object store to the catalog
     fn object_store(&self) -> Arc<dyn ObjectStore> {
@@ -199,16 +495,187 @@ impl Catalog for RestCatalog {
 
 #[cfg(test)]
 pub mod tests {
-    use std::sync::Arc;
+    use std::{collections::HashMap, sync::Arc};
 
     use iceberg_rs::{
-        catalog::{identifier::Identifier, Catalog},
+        catalog::{identifier::Identifier, namespace::Namespace, Catalog},
         model::schema::{AllType, PrimitiveType, SchemaStruct, SchemaV2, StructField},
         object_store::{memory::InMemory, ObjectStore},
         table::table_builder::TableBuilder,
     };
 
-    use crate::{apis::configuration::Configuration, catalog::RestCatalog};
+    use crate::{
+        apis::configuration::Configuration,
+        catalog::RestCatalog,
+        test_util::{empty_response, json_response, ServerStub},
+    };
+
+    #[tokio::test]
+    async fn test_initialize() {
+        let catalog = Arc::new(RestCatalog::new(
+            "my_catalog".to_owned(),
+            configuration(),
+            Arc::new(InMemory::new()),
+        ));
+        Arc::clone(&catalog)
+            .initialize(&HashMap::from([("clients".to_owned(), "8".to_owned())]))
+            .await
+            .unwrap();
+        assert_eq!(catalog.properties().get("clients").unwrap(), "8");
+        Arc::clone(&catalog)
+            .initialize(&HashMap::from([(
+                "uri".to_owned(),
+                "http://localhost:8181".to_owned(),
+            )]))
+            .await
+            .expect_err("The uri can't change");
+    }
+
+    #[tokio::test]
+    async fn test_list_pages() {
+        let server = ServerStub::start(vec![
+            json_response(
+                "200 OK",
+                r#"{"identifiers":[{"namespace":["sales"],"name":"orders"}],"next-page-token":"page-2"}"#,
+            ),
+            json_response(
+                "200 OK",
+                r#"{"identifiers":[{"namespace":["sales"],"name":"customers"}]}"#,
+            ),
+        ])
+        .await;
+        let catalog = RestCatalog::new(
+            "my_catalog".to_owned(),
+            Configuration {
+                base_path: server.uri.clone(),
+                ..Default::default()
+            },
+            Arc::new(InMemory::new()),
+        );
+        assert_eq!(catalog.capabilities().pagination(), None);
+        let tables = catalog
+            .list_tables(&Namespace::try_new(&["sales".to_owned()]).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            tables.iter().map(Identifier::name).collect::<Vec<_>>(),
+            vec!["orders", "customers"]
+        );
+        assert_eq!(catalog.capabilities().pagination(), Some(true));
+
+        let requests = server.requests().await;
+        assert_eq!(
+            requests[0].target,
+            "/v1/my_catalog/namespaces/sales/tables?pageToken="
+        );
+        assert_eq!(
+            requests[1].target,
+            "/v1/my_catalog/namespaces/sales/tables?pageToken=page-2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_without_endpoint() {
+        let server = ServerStub::start(vec![
+            json_response(
+                "405 Method Not Allowed",
+                r#"{"error":{"message":"Method not allowed","type":"MethodNotAllowedException","code":405}}"#,
+            ),
+            json_response(
+                "200 OK",
+                r#"{"metadata-location":"s3://bucket/sales/orders/metadata/00001.metadata.json",
+                    "metadata":{"format-version":2,"table-uuid":"9c12d441"}}"#,
+            ),
+        ])
+        .await;
+        let catalog = RestCatalog::new(
+            "my_catalog".to_owned(),
+            Configuration {
+                base_path: server.uri.clone(),
+                ..Default::default()
+            },
+            Arc::new(InMemory::new()),
+        );
+        catalog
+            .register_metadata_file(
+                &Identifier::parse("sales.orders").unwrap(),
+                "s3://bucket/sales/orders/metadata/00001.metadata.json",
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(
+            requests[0].target,
+            "/v1/my_catalog/namespaces/sales/register"
+        );
+        assert_eq!(requests[1].target, "/v1/my_catalog/namespaces/sales/tables");
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(
+            body["location"],
+            "s3://bucket/sales/orders/metadata/00001.metadata.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exists_without_head() {
+        let not_allowed = json_response(
+            "405 Method Not Allowed",
+            r#"{"error":{"message":"Method not allowed","type":"MethodNotAllowedException","code":405}}"#,
+        );
+        let not_found = json_response(
+            "404 Not Found",
+            r#"{"error":{"message":"Not found","type":"NoSuchTableException","code":404}}"#,
+        );
+        let server = ServerStub::start(vec![
+            not_allowed,
+            json_response("200 OK", r#"{"namespace":["sales"]}"#),
+            not_found.clone(),
+            empty_response("501 Not Implemented"),
+            json_response(
+                "200 OK",
+                r#"{"metadata-location":"s3://bucket/sales/orders/metadata/00001.metadata.json",
+                    "metadata":{"format-version":2,"table-uuid":"9c12d441"}}"#,
+            ),
+            not_found,
+        ])
+        .await;
+        let catalog = Arc::new(RestCatalog::new(
+            "my_catalog".to_owned(),
+            Configuration {
+                base_path: server.uri.clone(),
+                ..Default::default()
+            },
+            Arc::new(InMemory::new()),
+        ));
+        let sales = Namespace::try_new(&["sales".to_owned()]).unwrap();
+        let marketing = Namespace::try_new(&["marketing".to_owned()]).unwrap();
+        assert!(catalog.namespace_exists(&sales).await.unwrap());
+        assert!(!catalog.namespace_exists(&marketing).await.unwrap());
+        let orders = Identifier::parse("sales.orders").unwrap();
+        let customers = Identifier::parse("sales.customers").unwrap();
+        assert!(Arc::clone(&catalog).table_exists(&orders).await.unwrap());
+        assert!(!Arc::clone(&catalog).table_exists(&customers).await.unwrap());
+
+        // Rejected HEAD requests are not sent again
+        let requests: Vec<_> = server
+            .requests()
+            .await
+            .into_iter()
+            .map(|x| format!("{} {}", x.method, x.target))
+            .collect();
+        assert_eq!(
+            requests,
+            vec![
+                "HEAD /v1/my_catalog/namespaces/sales",
+                "GET /v1/my_catalog/namespaces/sales",
+                "GET /v1/my_catalog/namespaces/marketing",
+                "HEAD /v1/my_catalog/namespaces/sales/tables/orders",
+                "GET /v1/my_catalog/namespaces/sales/tables/orders?snapshots=refs",
+                "GET /v1/my_catalog/namespaces/sales/tables/customers?snapshots=refs",
+            ]
+        );
+    }
 
     fn configuration() -> Configuration {
         Configuration {
@@ -219,6 +686,7 @@ pub mod tests {
             oauth_access_token: None,
             bearer_access_token: None,
             api_key: None,
+            ..Default::default()
         }
     }
 
@@ -281,9 +749,10 @@ pub mod tests {
             .await
             .expect("Failed to drop table.");
 
-        Arc::clone(&catalog)
+        let exists = Arc::clone(&catalog)
             .table_exists(&identifier)
             .await
-            .expect_err("Table still exists");
+            .expect("Failed to check the table");
+        assert_eq!(exists, false);
     }
 }
@@ -0,0 +1,478 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use iceberg_rs::object_store::ObjectStore;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::apis::{
+    client::{
+        ClientOptions, CONNECTION_TIMEOUT_MS, HTTP2_PRIOR_KNOWLEDGE, POOL_IDLE_TIMEOUT_MS,
+        POOL_MAX_IDLE_PER_HOST, REQUEST_TIMEOUT_MS, TCP_KEEPALIVE_MS,
+    },
+    configuration::Configuration,
+    configuration_api_api,
+    interceptor::{HeaderInterceptor, InterceptorChain},
+    o_auth2_api_api,
+    proxy::{NO_PROXY, PROXY_FROM_ENV, PROXY_PASSWORD, PROXY_URI, PROXY_USERNAME},
+    tls::{CA_BUNDLE, CLIENT_CERT, CLIENT_CERT_PASSWORD, CLIENT_KEY, HOSTNAME_OVERRIDE},
+};
+
+#[cfg(feature = "config-file")]
+use super::profile::{default_config_file, load_profile};
+use super::{
+    capabilities::Capabilities,
+    profile::{properties_from_env, resolve_references},
+    reporter::{CatalogMetricsReporter, MetricsReporter},
+    session::SessionCache,
+    tables::TableCache,
+    RestCatalog,
+};
+
+/// Catalog property for the base uri of the REST catalog.
+pub const URI: &str = "uri";
+/// Catalog property for the warehouse requested from the server.
+pub const WAREHOUSE: &str = "warehouse";
+/// Catalog property for the prefix used in all catalog paths.
+pub const PREFIX: &str = "prefix";
+/// Catalog property for the OAuth2 client credential in the form `client_id:client_secret` or `client_secret`.
+pub const CREDENTIAL: &str = "credential";
+/// Catalog property for a bearer token.
+pub const TOKEN: &str = "token";
+/// Catalog property for the scope requested with the client credential.
+pub const SCOPE: &str = "scope";
+
+pub(crate) const DEFAULT_SCOPE: &str = "catalog";
+
+/// Builder for a [`RestCatalog`].
+///
+/// The builder collects catalog properties and client settings. Property values can reference environment
+/// variables and files, see [`resolve_references`]. [`RestCatalogBuilder::build`] validates them,
+/// exchanges a credential for a token if necessary and fetches the server configuration from `/v1/config`.
+pub struct RestCatalogBuilder {
+    name: String,
+    properties: HashMap<String, String>,
+    headers: Vec<(String, String)>,
+    client: Option<reqwest::Client>,
+    object_store: Option<Arc<dyn ObjectStore>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
+}
+
+impl RestCatalogBuilder {
+    pub fn new(name: &str) -> Self {
+        RestCatalogBuilder {
+            name: name.to_owned(),
+            properties: HashMap::new(),
+            headers: Vec::new(),
+            client: None,
+            object_store: None,
+            metrics_reporter: None,
+        }
+    }
+
+    /// Base uri of the REST catalog, for example `https://catalog.example.com/api`.
+    pub fn uri(self, uri: &str) -> Self {
+        self.property(URI, uri)
+    }
+
+    pub fn warehouse(self, warehouse: &str) -> Self {
+        self.property(WAREHOUSE, warehouse)
+    }
+
+    /// Prefix used in all catalog paths. Overridden by a prefix returned from the server.
+    pub fn prefix(self, prefix: &str) -> Self {
+        self.property(PREFIX, prefix)
+    }
+
+    /// OAuth2 client credential in the form `client_id:client_secret` or `client_secret`.
+    pub fn credential(self, credential: &str) -> Self {
+        self.property(CREDENTIAL, credential)
+    }
+
+    /// Bearer token used for all requests. Takes precedence over a credential.
+    pub fn token(self, token: &str) -> Self {
+        self.property(TOKEN, token)
+    }
+
+    pub fn scope(self, scope: &str) -> Self {
+        self.property(SCOPE, scope)
+    }
+
+    /// Set an arbitrary catalog property.
+    pub fn property(mut self, key: &str, value: &str) -> Self {
+        self.properties.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Set multiple catalog properties. Existing properties with the same key are replaced.
+    pub fn properties(mut self, properties: HashMap<String, String>) -> Self {
+        self.properties.extend(properties);
+        self
+    }
+
+    /// Add catalog properties from `ICEBERG_REST_*` environment variables, see [`properties_from_env`].
+    pub fn with_env(self) -> Self {
+        self.properties(properties_from_env())
+    }
+
+    /// Add the properties of the catalog profile `name` from a YAML or TOML configuration file.
+    #[cfg(feature = "config-file")]
+    pub fn profile(self, path: &Path, name: &str) -> Result<Self> {
+        Ok(self.properties(load_profile(path, name)?))
+    }
+
+    /// Create a builder from the profile with the catalog name in the default configuration file,
+    /// if the file exists, followed by the `ICEBERG_REST_*` environment variables.
+    #[cfg(feature = "config-file")]
+    pub fn load(name: &str) -> Result<Self> {
+        let builder = match default_config_file() {
+            Some(path) => RestCatalogBuilder::new(name).profile(&path, name)?,
+            None => RestCatalogBuilder::new(name),
+        };
+        Ok(builder.with_env())
+    }
+
+    /// Add a header that is sent with every request.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Use a custom http client. Client options configured on the builder are ignored in this case,
+    /// except for the timeouts of single operations.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn connect_timeout(self, timeout: Duration) -> Self {
+        self.property(CONNECTION_TIMEOUT_MS, &timeout.as_millis().to_string())
+    }
+
+    /// Timeout for a whole request, from connecting until the response body has been read.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.property(REQUEST_TIMEOUT_MS, &timeout.as_millis().to_string())
+    }
+
+    /// Timeout for all requests of a single operation, like `update_table`, instead of the default timeout.
+    pub fn operation_timeout(self, operation: &str, timeout: Duration) -> Self {
+        self.property(
+            &format!("{}.{}", REQUEST_TIMEOUT_MS, operation),
+            &timeout.as_millis().to_string(),
+        )
+    }
+
+    pub fn pool_idle_timeout(self, timeout: Duration) -> Self {
+        self.property(POOL_IDLE_TIMEOUT_MS, &timeout.as_millis().to_string())
+    }
+
+    pub fn pool_max_idle_per_host(self, max: usize) -> Self {
+        self.property(POOL_MAX_IDLE_PER_HOST, &max.to_string())
+    }
+
+    pub fn tcp_keepalive(self, interval: Duration) -> Self {
+        self.property(TCP_KEEPALIVE_MS, &interval.as_millis().to_string())
+    }
+
+    pub fn http2_prior_knowledge(self) -> Self {
+        self.property(HTTP2_PRIOR_KNOWLEDGE, "true")
+    }
+
+    /// PEM bundle with CA certificates that are trusted in addition to the system roots.
+    pub fn ca_bundle(self, path: &Path) -> Self {
+        self.property(CA_BUNDLE, &path.to_string_lossy())
+    }
+
+    /// Client certificate for mutual TLS, from a PEM certificate chain and a PEM private key.
+    pub fn client_cert_pem(self, cert: &Path, key: &Path) -> Self {
+        self.property(CLIENT_CERT, &cert.to_string_lossy())
+            .property(CLIENT_KEY, &key.to_string_lossy())
+    }
+
+    /// Client certificate for mutual TLS, from a PKCS#12 archive with file extension `.p12` or `.pfx`.
+    /// Requires the `native-tls` feature.
+    pub fn client_cert_pkcs12(self, path: &Path, password: &str) -> Self {
+        self.property(CLIENT_CERT, &path.to_string_lossy())
+            .property(CLIENT_CERT_PASSWORD, password)
+    }
+
+    /// Verify the server certificate against `hostname` instead of the host of the uri.
+    /// Requests are still sent to the address of the uri host.
+    pub fn hostname_override(self, hostname: &str) -> Self {
+        self.property(HOSTNAME_OVERRIDE, hostname)
+    }
+
+    /// Send all requests through the proxy at `uri`, for example `http://proxy.example.com:3128`.
+    pub fn proxy(self, uri: &str) -> Self {
+        self.property(PROXY_URI, uri)
+    }
+
+    pub fn proxy_basic_auth(self, username: &str, password: &str) -> Self {
+        self.property(PROXY_USERNAME, username)
+            .property(PROXY_PASSWORD, password)
+    }
+
+    /// Comma separated list of hosts, domains and ip ranges that are not sent through the proxy.
+    pub fn no_proxy(self, no_proxy: &str) -> Self {
+        self.property(NO_PROXY, no_proxy)
+    }
+
+    /// Use the proxies from the `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` environment variables.
+    pub fn proxy_from_env(self) -> Self {
+        self.property(PROXY_FROM_ENV, "true")
+    }
+
+    pub fn object_store(mut self, object_store: Arc<dyn ObjectStore>) -> Self {
+        self.object_store = Some(object_store);
+        self
+    }
+
+    /// Send a commit report to `reporter` after every successful table update. Reports are sent in the
+    /// background, commits don't wait for them.
+    pub fn metrics_reporter(mut self, reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(reporter);
+        self
+    }
+
+    /// Validate the configuration, authenticate and fetch the catalog configuration from the server.
+    pub async fn build(self) -> Result<RestCatalog> {
+        let client_properties = resolve_references(self.properties)?;
+        let object_store = self
+            .object_store
+            .ok_or_else(|| anyhow!("No object store configured for catalog {}.", self.name))?;
+        let uri = client_properties
+            .get(URI)
+            .ok_or_else(|| anyhow!("No uri configured for catalog {}.", self.name))?;
+        url::Url::parse(uri).map_err(|err| anyhow!("Invalid uri {}: {}", uri, err))?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes())?,
+                HeaderValue::from_str(value)?,
+            );
+        }
+        let interceptors = if headers.is_empty() {
+            InterceptorChain::new()
+        } else {
+            InterceptorChain::new().with(HeaderInterceptor::new(headers))
+        };
+
+        let client_options = ClientOptions::from_properties(&client_properties).map_err(anyhow::Error::msg)?;
+        let (client, base_path) = match self.client {
+            Some(ref client) => (client.clone(), uri.trim_end_matches('/').to_owned()),
+            None => client_options
+                .build_client(uri.trim_end_matches('/'))
+                .map_err(|err| anyhow!(err))?,
+        };
+
+        let mut configuration = Configuration {
+            base_path,
+            client,
+            operation_timeouts: client_options.operation_timeouts.clone(),
+            oauth_access_token: client_properties.get(TOKEN).map(|x| x.as_str().into()),
+            interceptors,
+            ..Default::default()
+        };
+        #[cfg(feature = "sigv4")]
+        {
+            configuration.sigv4_signer =
+                crate::apis::sigv4::SigV4Signer::from_properties(&client_properties)
+                    .map_err(|err| anyhow!(err))?;
+        }
+
+        if configuration.oauth_access_token.is_none() {
+            if let Some(credential) = client_properties.get(CREDENTIAL) {
+                let (client_id, client_secret) = match credential.split_once(':') {
+                    Some((client_id, client_secret)) => (Some(client_id), client_secret),
+                    None => (None, credential.as_str()),
+                };
+                let scope = client_properties
+                    .get(SCOPE)
+                    .map(String::as_str)
+                    .unwrap_or(DEFAULT_SCOPE);
+                let token = o_auth2_api_api::get_token(
+                    &configuration,
+                    Some("client_credentials"),
+                    Some(scope),
+                    client_id,
+                    Some(client_secret),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                configuration.oauth_access_token = Some(token.access_token);
+            }
+        }
+
+        let config = configuration_api_api::get_config(
+            &configuration,
+            client_properties.get(WAREHOUSE).map(String::as_str),
+        )
+        .await?;
+        let properties = merge_properties(&config.defaults, &client_properties, &config.overrides);
+
+        let server_uri = properties.get(URI).filter(|server_uri| *server_uri != uri);
+        if let Some(uri) = server_uri {
+            url::Url::parse(uri).map_err(|err| anyhow!("Invalid uri {}: {}", uri, err))?;
+            configuration.base_path = uri.trim_end_matches('/').to_owned();
+        }
+        // The server configuration can change the client options
+        let options = ClientOptions::from_properties(&properties).map_err(anyhow::Error::msg)?;
+        if options != client_options || server_uri.is_some() {
+            configuration.operation_timeouts = options.operation_timeouts.clone();
+            if self.client.is_none() {
+                let base_path = properties.get(URI).unwrap_or(uri).trim_end_matches('/');
+                let (client, base_path) = options
+                    .build_client(base_path)
+                    .map_err(|err| anyhow!(err))?;
+                configuration.client = client;
+                configuration.base_path = base_path;
+            }
+        }
+        #[cfg(feature = "sigv4")]
+        {
+            configuration.sigv4_signer =
+                crate::apis::sigv4::SigV4Signer::from_properties(&properties)
+                    .map_err(|err| anyhow!(err))?;
+        }
+        let prefix = properties
+            .get(PREFIX)
+            .cloned()
+            .unwrap_or_else(|| self.name.clone());
+
+        Ok(RestCatalog {
+            name: self.name,
+            prefix,
+            configuration,
+            object_store,
+            properties: RwLock::new(properties),
+            sessions: SessionCache::default(),
+            metrics_reporter: self.metrics_reporter.map(CatalogMetricsReporter::new),
+            capabilities: Capabilities::from_config(&config),
+            head_rejected: Arc::default(),
+            tables: TableCache::default(),
+            config,
+        })
+    }
+}
+
+/// Merge the server configuration with the client properties.
+/// Defaults are applied first, then the client properties and finally the overrides.
+pub(crate) fn merge_properties(
+    defaults: &HashMap<String, String>,
+    properties: &HashMap<String, String>,
+    overrides: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut merged = defaults.clone();
+    merged.extend(properties.clone());
+    merged.extend(overrides.clone());
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use iceberg_rs::object_store::memory::InMemory;
+
+    use super::{merge_properties, RestCatalogBuilder};
+    use crate::test_util::{json_response, ServerStub};
+
+    #[tokio::test]
+    async fn test_build_without_uri() {
+        RestCatalogBuilder::new("my_catalog")
+            .object_store(Arc::new(InMemory::new()))
+            .build()
+            .await
+            .err()
+            .expect("Catalog without uri");
+    }
+
+    #[tokio::test]
+    async fn test_build_with_config_handshake() {
+        let server = ServerStub::start(vec![
+            json_response(
+                "200 OK",
+                r#"{"access_token":"catalog-token","token_type":"bearer","expires_in":3600}"#,
+            ),
+            json_response(
+                "200 OK",
+                r#"{"defaults":{"clients":"4","warehouse":"s3://default"},"overrides":{"prefix":"warehouse_a"}}"#,
+            ),
+        ])
+        .await;
+        let catalog = RestCatalogBuilder::new("my_catalog")
+            .uri(&server.uri)
+            .credential("client:secret")
+            .warehouse("s3://warehouse")
+            .prefix("client_prefix")
+            .object_store(Arc::new(InMemory::new()))
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(catalog.prefix, "warehouse_a");
+        assert_eq!(catalog.properties().get("clients").unwrap(), "4");
+        assert_eq!(
+            catalog.properties().get("warehouse").unwrap(),
+            "s3://warehouse"
+        );
+
+        let requests = server.requests().await;
+        assert_eq!(requests[0].target, "/v1/oauth/tokens");
+        assert!(
+            String::from_utf8_lossy(&requests[0].body).contains("grant_type=client_credentials")
+        );
+        assert_eq!(
+            requests[1].target,
+            "/v1/config?warehouse=s3%3A%2F%2Fwarehouse"
+        );
+        assert_eq!(
+            requests[1].header("authorization"),
+            Some("Bearer catalog-token")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_with_failed_handshake() {
+        let server = ServerStub::start(vec![json_response(
+            "401 Unauthorized",
+            r#"{"error":{"message":"Not authorized","type":"NotAuthorizedException","code":401}}"#,
+        )])
+        .await;
+        let error = RestCatalogBuilder::new("my_catalog")
+            .uri(&server.uri)
+            .token("expired-token")
+            .object_store(Arc::new(InMemory::new()))
+            .build()
+            .await
+            .err()
+            .expect("Configuration rejected by the server");
+        assert!(error.to_string().contains("401"));
+        assert_eq!(server.requests().await[0].target, "/v1/config");
+    }
+
+    #[test]
+    fn test_merge_properties() {
+        let defaults = HashMap::from([
+            ("clients".to_owned(), "4".to_owned()),
+            ("warehouse".to_owned(), "s3://default".to_owned()),
+        ]);
+        let overrides = HashMap::from([("prefix".to_owned(), "my_prefix".to_owned())]);
+        let properties = HashMap::from([
+            ("warehouse".to_owned(), "s3://client".to_owned()),
+            ("prefix".to_owned(), "client".to_owned()),
+        ]);
+        let merged = merge_properties(&defaults, &properties, &overrides);
+        assert_eq!(merged.get("clients").unwrap(), "4");
+        assert_eq!(merged.get("warehouse").unwrap(), "s3://client");
+        assert_eq!(merged.get("prefix").unwrap(), "my_prefix");
+    }
+}
@@ -0,0 +1,143 @@
+//! Optional features of the catalog server.
+//!
+//! Servers advertise the endpoints they support in the `endpoints` list of their configuration. Calls to
+//! endpoints missing from the list fail with [`Unsupported`] before a request is sent. Servers that don't send
+//! the list are not restricted.
+//!
+//! Pagination of listings is not advertised. Listings always request pages, servers that support pagination
+//! answer with a `next-page-token`, the others with all results at once. The capability is known once a
+//! listing returned a token.
+
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::models::CatalogConfig;
+
+pub const NAMESPACE_EXISTS: &str = "HEAD /v1/{prefix}/namespaces/{namespace}";
+pub const REGISTER_TABLE: &str = "POST /v1/{prefix}/namespaces/{namespace}/register";
+pub const COMMIT_TRANSACTION: &str = "POST /v1/{prefix}/transactions/commit";
+pub const LIST_VIEWS: &str = "GET /v1/{prefix}/namespaces/{namespace}/views";
+pub const CREATE_VIEW: &str = "POST /v1/{prefix}/namespaces/{namespace}/views";
+pub const LOAD_VIEW: &str = "GET /v1/{prefix}/namespaces/{namespace}/views/{view}";
+pub const REPLACE_VIEW: &str = "POST /v1/{prefix}/namespaces/{namespace}/views/{view}";
+pub const DROP_VIEW: &str = "DELETE /v1/{prefix}/namespaces/{namespace}/views/{view}";
+pub const VIEW_EXISTS: &str = "HEAD /v1/{prefix}/namespaces/{namespace}/views/{view}";
+pub const RENAME_VIEW: &str = "POST /v1/{prefix}/views/rename";
+
+/// Features supported by the catalog server.
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    endpoints: Option<HashSet<String>>,
+    /// A listing returned a `next-page-token`. Shared by the catalogs of all sessions.
+    pagination: Arc<AtomicBool>,
+}
+
+impl Capabilities {
+    pub fn from_config(config: &CatalogConfig) -> Self {
+        Capabilities {
+            endpoints: config
+                .endpoints
+                .as_ref()
+                .map(|endpoints| endpoints.iter().cloned().collect()),
+            pagination: Arc::default(),
+        }
+    }
+
+    /// Endpoints advertised by the server, `None` if it didn't send the list.
+    pub fn endpoints(&self) -> Option<&HashSet<String>> {
+        self.endpoints.as_ref()
+    }
+
+    /// Returns `Some(true)` if the server advertised `endpoint`, like `POST /v1/{prefix}/transactions/commit`,
+    /// and `None` if the server didn't advertise any endpoints.
+    pub fn supports(&self, endpoint: &str) -> Option<bool> {
+        self.endpoints
+            .as_ref()
+            .map(|endpoints| endpoints.contains(endpoint))
+    }
+
+    /// Views can be loaded, see [`LOAD_VIEW`].
+    pub fn views(&self) -> Option<bool> {
+        self.supports(LOAD_VIEW)
+    }
+
+    /// Existing metadata files can be registered as tables, see [`REGISTER_TABLE`].
+    pub fn register_table(&self) -> Option<bool> {
+        self.supports(REGISTER_TABLE)
+    }
+
+    /// Changes of several tables can be committed atomically, see [`COMMIT_TRANSACTION`].
+    pub fn multi_table_commit(&self) -> Option<bool> {
+        self.supports(COMMIT_TRANSACTION)
+    }
+
+    /// The existence of a namespace can be checked without loading it, see [`NAMESPACE_EXISTS`].
+    pub fn namespace_exists(&self) -> Option<bool> {
+        self.supports(NAMESPACE_EXISTS)
+    }
+
+    /// Listings are paginated, `Some(true)` once a listing returned a `next-page-token` and `None` until then.
+    pub fn pagination(&self) -> Option<bool> {
+        self.pagination.load(Ordering::Relaxed).then(|| true)
+    }
+
+    /// Record that a listing returned a `next-page-token`.
+    pub(crate) fn record_pagination(&self) {
+        self.pagination.store(true, Ordering::Relaxed);
+    }
+
+    /// Fails with [`Unsupported`] if the server advertised its endpoints without `endpoint`.
+    pub fn check(&self, endpoint: &'static str) -> Result<(), Unsupported> {
+        match self.supports(endpoint) {
+            Some(false) => Err(Unsupported { endpoint }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The catalog server doesn't support an endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsupported {
+    pub endpoint: &'static str,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the catalog server does not support {}", self.endpoint)
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Capabilities, Unsupported, COMMIT_TRANSACTION, LOAD_VIEW};
+    use crate::models::CatalogConfig;
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = Capabilities::from_config(&CatalogConfig::default());
+        assert_eq!(capabilities.views(), None);
+        assert!(capabilities.check(LOAD_VIEW).is_ok());
+
+        let mut config = CatalogConfig::new(HashMap::new(), HashMap::new());
+        config.endpoints = Some(vec![COMMIT_TRANSACTION.to_owned()]);
+        let capabilities = Capabilities::from_config(&config);
+        assert_eq!(capabilities.multi_table_commit(), Some(true));
+        assert_eq!(capabilities.views(), Some(false));
+        assert_eq!(
+            capabilities.check(LOAD_VIEW),
+            Err(Unsupported {
+                endpoint: LOAD_VIEW
+            })
+        );
+    }
+}
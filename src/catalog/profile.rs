@@ -0,0 +1,174 @@
+//! Load catalog properties from the environment and from configuration files with named catalog profiles.
+//!
+//! A configuration file lists catalogs under the `catalog` key, for example in YAML:
+//!
+//! ```yaml
+//! catalog:
+//!   prod:
+//!     uri: https://catalog.example.com/api
+//!     credential: ${env:PROD_CATALOG_CREDENTIAL}
+//!     rest.signing-region: us-east-1
+//! ```
+//!
+//! Any property value of the form `${env:NAME}` is replaced by the environment variable `NAME`
+//! and `${file:PATH}` by the trimmed content of the file at `PATH`.
+
+use std::collections::HashMap;
+#[cfg(feature = "config-file")]
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+
+/// Prefix of environment variables that are read as catalog properties.
+pub const ENV_PREFIX: &str = "ICEBERG_REST_";
+/// Environment variable with the path of the configuration file.
+pub const CONFIG_FILE_ENV: &str = "ICEBERG_REST_CONFIG_FILE";
+
+#[cfg(feature = "config-file")]
+const DEFAULT_CONFIG_FILES: [&str; 3] = [".iceberg-rest.yaml", ".iceberg-rest.yml", ".iceberg-rest.toml"];
+
+/// Read catalog properties from all environment variables starting with `ICEBERG_REST_`.
+///
+/// The rest of the variable name is lowercased, `__` is replaced by `.` and `_` by `-`.
+/// For example `ICEBERG_REST_URI` sets `uri` and `ICEBERG_REST_REST__SIGNING_REGION` sets `rest.signing-region`.
+pub fn properties_from_env() -> HashMap<String, String> {
+    properties_from_vars(std::env::vars())
+}
+
+fn properties_from_vars(vars: impl Iterator<Item = (String, String)>) -> HashMap<String, String> {
+    vars.filter(|(key, _)| key != CONFIG_FILE_ENV)
+        .filter_map(|(key, value)| {
+            key.strip_prefix(ENV_PREFIX)
+                .filter(|name| !name.is_empty())
+                .map(|name| {
+                    (
+                        name.to_lowercase().replace("__", ".").replace('_', "-"),
+                        value,
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Replace `${env:NAME}` and `${file:PATH}` references in the property values.
+pub fn resolve_references(properties: HashMap<String, String>) -> Result<HashMap<String, String>> {
+    resolve_references_with(properties, |name| std::env::var(name).ok())
+}
+
+fn resolve_references_with(
+    properties: HashMap<String, String>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<HashMap<String, String>> {
+    properties
+        .into_iter()
+        .map(|(key, value)| -> Result<(String, String)> {
+            let reference = value
+                .strip_prefix("${")
+                .and_then(|x| x.strip_suffix('}'))
+                .and_then(|x| x.split_once(':'));
+            let resolved = match reference {
+                Some(("env", name)) => Some(env(name).ok_or_else(|| {
+                    anyhow!("Environment variable {} referenced by property {} is not set.", name, key)
+                })?),
+                Some(("file", path)) => Some(
+                    std::fs::read_to_string(path)
+                        .map_err(|err| {
+                            anyhow!("Failed to read file {} referenced by property {}: {}", path, key, err)
+                        })?
+                        .trim()
+                        .to_owned(),
+                ),
+                _ => None,
+            };
+            Ok((key, resolved.unwrap_or(value)))
+        })
+        .collect()
+}
+
+#[cfg(feature = "config-file")]
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    catalog: HashMap<String, serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Read the properties of the catalog profile `name` from a YAML or TOML file.
+/// The format is chosen by the file extension.
+#[cfg(feature = "config-file")]
+pub fn load_profile(path: &Path, name: &str) -> Result<HashMap<String, String>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| anyhow!("Failed to read {}: {}", path.display(), err))?;
+    let file: ConfigFile = match path.extension().and_then(|x| x.to_str()) {
+        Some("toml") => toml::from_str(&content)?,
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&content)?,
+        _ => return Err(anyhow!("Unknown configuration file format of {}.", path.display())),
+    };
+    file.catalog
+        .get(name)
+        .map(profile_properties)
+        .ok_or_else(|| anyhow!("No catalog {} in {}.", name, path.display()))
+}
+
+/// Profile values that are not strings, like numbers or booleans, are converted to their JSON representation.
+#[cfg(feature = "config-file")]
+fn profile_properties(
+    profile: &serde_json::Map<String, serde_json::Value>,
+) -> HashMap<String, String> {
+    profile
+        .iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(value) => (key.clone(), value.clone()),
+            value => (key.clone(), value.to_string()),
+        })
+        .collect()
+}
+
+/// Location of the configuration file: `ICEBERG_REST_CONFIG_FILE` if set, otherwise the first existing
+/// `.iceberg-rest.yaml`, `.iceberg-rest.yml` or `.iceberg-rest.toml` in the home directory.
+#[cfg(feature = "config-file")]
+pub fn default_config_file() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(CONFIG_FILE_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    DEFAULT_CONFIG_FILES
+        .iter()
+        .map(|file| Path::new(&home).join(file))
+        .find(|path| path.exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{properties_from_vars, resolve_references_with};
+
+    #[test]
+    fn test_properties_from_vars() {
+        let vars = vec![
+            ("ICEBERG_REST_URI".to_owned(), "http://localhost:8080".to_owned()),
+            ("ICEBERG_REST_REST__SIGNING_REGION".to_owned(), "us-east-1".to_owned()),
+            ("HOME".to_owned(), "/root".to_owned()),
+        ];
+        let properties = properties_from_vars(vars.into_iter());
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties.get("uri").unwrap(), "http://localhost:8080");
+        assert_eq!(properties.get("rest.signing-region").unwrap(), "us-east-1");
+    }
+
+    #[test]
+    fn test_resolve_references() {
+        let env = HashMap::from([("CATALOG_CREDENTIAL".to_owned(), "client:secret".to_owned())]);
+        let lookup = |name: &str| env.get(name).cloned();
+        let properties = HashMap::from([
+            ("credential".to_owned(), "${env:CATALOG_CREDENTIAL}".to_owned()),
+            ("warehouse".to_owned(), "file:///tmp/warehouse".to_owned()),
+        ]);
+        let resolved = resolve_references_with(properties, lookup).unwrap();
+        assert_eq!(resolved.get("credential").unwrap(), "client:secret");
+        assert_eq!(resolved.get("warehouse").unwrap(), "file:///tmp/warehouse");
+
+        let properties = HashMap::from([("token".to_owned(), "${env:CATALOG_TOKEN}".to_owned())]);
+        resolve_references_with(properties, lookup).expect_err("Variable is not set");
+    }
+}
@@ -0,0 +1,646 @@
+//! Reporting of scan and commit metrics to the catalog.
+//!
+//! Reports are built with [`ScanReportBuilder`] and [`CommitReportBuilder`] and handed to a [`MetricsReporter`].
+//! Wrap the reporter in a [`BufferedMetricsReporter`] to send them in the background, so that queries
+//! and commits never wait for the catalog to accept a report. The reporter of a [`RestCatalog`] is
+//! always buffered.
+
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures::{channel::mpsc, Future, StreamExt};
+use iceberg_rs::catalog::identifier::Identifier;
+
+use crate::{
+    apis::{catalog_api_api, configuration::Configuration},
+    models::{
+        CommitMetrics, CommitReport, CounterResult, Expression, ReportMetricsRequest, ScanMetrics,
+        ScanReport, Schema, TableMetadata, TimerResult,
+    },
+};
+
+use super::RestCatalog;
+
+/// Sends metrics reports of table operations.
+#[async_trait]
+pub trait MetricsReporter: Send + Sync {
+    /// Report metrics of an operation on the table `identifier`.
+    async fn report(&self, identifier: &Identifier, report: ReportMetricsRequest) -> Result<()>;
+}
+
+/// Posts reports to the `/metrics` endpoint of the table.
+#[derive(Debug, Clone)]
+pub struct RestMetricsReporter {
+    configuration: Configuration,
+    prefix: String,
+}
+
+impl RestMetricsReporter {
+    pub fn new(configuration: Configuration, prefix: &str) -> Self {
+        RestMetricsReporter {
+            configuration,
+            prefix: prefix.to_owned(),
+        }
+    }
+}
+
+#[async_trait]
+impl MetricsReporter for RestMetricsReporter {
+    async fn report(&self, identifier: &Identifier, report: ReportMetricsRequest) -> Result<()> {
+        catalog_api_api::report_metrics(
+            &self.configuration,
+            &self.prefix,
+            &identifier.namespace().to_string(),
+            identifier.name(),
+            report,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Logs reports as JSON at info level.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Default)]
+pub struct LoggingMetricsReporter;
+
+#[cfg(feature = "tracing")]
+#[async_trait]
+impl MetricsReporter for LoggingMetricsReporter {
+    async fn report(&self, identifier: &Identifier, report: ReportMetricsRequest) -> Result<()> {
+        tracing::info!(
+            namespace = %identifier.namespace(),
+            table = identifier.name(),
+            report = %serde_json::to_string(&report)?,
+            "metrics report"
+        );
+        Ok(())
+    }
+}
+
+/// Discards all reports.
+#[derive(Debug, Clone, Default)]
+pub struct NoopMetricsReporter;
+
+#[async_trait]
+impl MetricsReporter for NoopMetricsReporter {
+    async fn report(&self, _identifier: &Identifier, _report: ReportMetricsRequest) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Buffers reports and forwards them to another reporter in the background.
+///
+/// Reporting only enqueues the report. If the buffer is full, the report is dropped instead of waiting.
+pub struct BufferedMetricsReporter {
+    sender: Mutex<mpsc::Sender<(Identifier, ReportMetricsRequest)>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl BufferedMetricsReporter {
+    /// Create a reporter that buffers up to `capacity` reports for `reporter`.
+    ///
+    /// Returns the reporter and the future that forwards the reports. The future has to be spawned on the
+    /// runtime of the application and completes once the reporter is dropped and all reports are forwarded.
+    /// Failed reports are not retried.
+    pub fn new(
+        reporter: Arc<dyn MetricsReporter>,
+        capacity: usize,
+    ) -> (Self, impl Future<Output = ()> + Send) {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let worker = receiver.for_each(move |(identifier, report): (Identifier, ReportMetricsRequest)| {
+            let reporter = Arc::clone(&reporter);
+            async move {
+                let result = reporter.report(&identifier, report).await;
+                #[cfg(feature = "tracing")]
+                if let Err(err) = result {
+                    tracing::warn!(
+                        namespace = %identifier.namespace(),
+                        table = identifier.name(),
+                        error = %err,
+                        "failed to send metrics report"
+                    );
+                }
+                #[cfg(not(feature = "tracing"))]
+                drop(result);
+            }
+        });
+        (
+            BufferedMetricsReporter {
+                sender: Mutex::new(sender),
+                dropped: Arc::new(AtomicU64::new(0)),
+            },
+            worker,
+        )
+    }
+
+    /// Number of reports that were dropped because the buffer was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait]
+impl MetricsReporter for BufferedMetricsReporter {
+    async fn report(&self, identifier: &Identifier, report: ReportMetricsRequest) -> Result<()> {
+        let result = self
+            .sender
+            .lock()
+            .map_err(|_| anyhow!("Metrics reporter is poisoned."))?
+            .try_send((identifier.clone(), report));
+        match result {
+            Ok(()) => Ok(()),
+            Err(err) if err.is_full() => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(_) => Err(anyhow!("Metrics reporter has been shut down.")),
+        }
+    }
+}
+
+/// Number of reports the reporter of a catalog buffers.
+const CATALOG_BUFFER_CAPACITY: usize = 64;
+
+type Worker = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Buffered reporter of a catalog. The worker forwarding the reports is spawned with the first report,
+/// so that the catalog can be created outside of a runtime.
+#[derive(Clone)]
+pub(crate) struct CatalogMetricsReporter {
+    reporter: Arc<BufferedMetricsReporter>,
+    worker: Arc<Mutex<Option<Worker>>>,
+}
+
+impl CatalogMetricsReporter {
+    pub(crate) fn new(reporter: Arc<dyn MetricsReporter>) -> Self {
+        let (reporter, worker) = BufferedMetricsReporter::new(reporter, CATALOG_BUFFER_CAPACITY);
+        CatalogMetricsReporter {
+            reporter: Arc::new(reporter),
+            worker: Arc::new(Mutex::new(Some(Box::pin(worker)))),
+        }
+    }
+
+    /// Enqueue a report without waiting for it to be sent. Failed reports are ignored.
+    ///
+    /// Reports are dropped while the worker can't be spawned because there is no tokio runtime.
+    pub(crate) async fn report(&self, identifier: &Identifier, report: ReportMetricsRequest) {
+        {
+            let mut worker = self.worker.lock().unwrap_or_else(PoisonError::into_inner);
+            if worker.is_some() {
+                match tokio::runtime::Handle::try_current() {
+                    Ok(handle) => {
+                        handle.spawn(worker.take().expect("Worker is present"));
+                    }
+                    Err(_err) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(
+                            namespace = %identifier.namespace(),
+                            table = identifier.name(),
+                            error = %_err,
+                            "dropped metrics report outside of a tokio runtime"
+                        );
+                        return;
+                    }
+                }
+            }
+        }
+        let _ = self.reporter.report(identifier, report).await;
+    }
+}
+
+fn timer(duration: Duration) -> TimerResult {
+    TimerResult::from_duration(1, duration)
+}
+
+/// Builds the report of a table scan.
+#[derive(Debug, Clone)]
+pub struct ScanReportBuilder {
+    report: ScanReport,
+}
+
+impl ScanReportBuilder {
+    pub fn new(table_name: &str, snapshot_id: i64) -> Self {
+        ScanReportBuilder {
+            report: ScanReport::new(
+                table_name.to_owned(),
+                snapshot_id,
+                Expression::default(),
+                Schema::default(),
+                ScanMetrics::new(),
+            ),
+        }
+    }
+
+    pub fn filter(mut self, filter: Expression) -> Self {
+        self.report.filter = Box::new(filter);
+        self
+    }
+
+    pub fn projection(mut self, projection: Schema) -> Self {
+        self.report.projection = Box::new(projection);
+        self
+    }
+
+    pub fn planning_duration(mut self, duration: Duration) -> Self {
+        self.report.metrics.total_planning_duration = Some(timer(duration));
+        self
+    }
+
+    pub fn result_data_files(mut self, count: i64) -> Self {
+        self.report.metrics.result_data_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn result_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.result_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_data_manifests(mut self, count: i64) -> Self {
+        self.report.metrics.total_data_manifests = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_delete_manifests(mut self, count: i64) -> Self {
+        self.report.metrics.total_delete_manifests = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn scanned_data_manifests(mut self, count: i64) -> Self {
+        self.report.metrics.scanned_data_manifests = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn skipped_data_manifests(mut self, count: i64) -> Self {
+        self.report.metrics.skipped_data_manifests = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn scanned_delete_manifests(mut self, count: i64) -> Self {
+        self.report.metrics.scanned_delete_manifests = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn skipped_delete_manifests(mut self, count: i64) -> Self {
+        self.report.metrics.skipped_delete_manifests = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn skipped_data_files(mut self, count: i64) -> Self {
+        self.report.metrics.skipped_data_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn skipped_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.skipped_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_file_size_in_bytes(mut self, bytes: i64) -> Self {
+        self.report.metrics.total_file_size_in_bytes = Some(CounterResult::bytes(bytes));
+        self
+    }
+
+    pub fn total_delete_file_size_in_bytes(mut self, bytes: i64) -> Self {
+        self.report.metrics.total_delete_file_size_in_bytes = Some(CounterResult::bytes(bytes));
+        self
+    }
+
+    pub fn indexed_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.indexed_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn equality_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.equality_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn positional_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.positional_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn build(self) -> ReportMetricsRequest {
+        ReportMetricsRequest::ScanReport(self.report)
+    }
+}
+
+/// Builds the report of a commit.
+#[derive(Debug, Clone)]
+pub struct CommitReportBuilder {
+    report: CommitReport,
+}
+
+impl CommitReportBuilder {
+    /// `operation` is the snapshot operation of the commit, like `append`.
+    pub fn new(table_name: &str, snapshot_id: i64, sequence_number: i64, operation: &str) -> Self {
+        CommitReportBuilder {
+            report: CommitReport::new(
+                table_name.to_owned(),
+                snapshot_id,
+                sequence_number,
+                operation.to_owned(),
+                CommitMetrics::new(),
+            ),
+        }
+    }
+
+    pub fn total_duration(mut self, duration: Duration) -> Self {
+        self.report.metrics.total_duration = Some(timer(duration));
+        self
+    }
+
+    /// Number of attempts it took to commit, including the successful one.
+    pub fn attempts(mut self, count: i64) -> Self {
+        self.report.metrics.attempts = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn added_data_files(mut self, count: i64) -> Self {
+        self.report.metrics.added_data_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn removed_data_files(mut self, count: i64) -> Self {
+        self.report.metrics.removed_data_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_data_files(mut self, count: i64) -> Self {
+        self.report.metrics.total_data_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn added_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.added_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn added_equality_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.added_equality_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn added_positional_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.added_positional_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn removed_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.removed_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn removed_equality_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.removed_equality_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn removed_positional_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.removed_positional_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_delete_files(mut self, count: i64) -> Self {
+        self.report.metrics.total_delete_files = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn added_records(mut self, count: i64) -> Self {
+        self.report.metrics.added_records = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn removed_records(mut self, count: i64) -> Self {
+        self.report.metrics.removed_records = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_records(mut self, count: i64) -> Self {
+        self.report.metrics.total_records = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn added_files_size_in_bytes(mut self, bytes: i64) -> Self {
+        self.report.metrics.added_files_size_in_bytes = Some(CounterResult::bytes(bytes));
+        self
+    }
+
+    pub fn removed_files_size_in_bytes(mut self, bytes: i64) -> Self {
+        self.report.metrics.removed_files_size_in_bytes = Some(CounterResult::bytes(bytes));
+        self
+    }
+
+    pub fn total_files_size_in_bytes(mut self, bytes: i64) -> Self {
+        self.report.metrics.total_files_size_in_bytes = Some(CounterResult::bytes(bytes));
+        self
+    }
+
+    pub fn added_positional_deletes(mut self, count: i64) -> Self {
+        self.report.metrics.added_positional_deletes = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn removed_positional_deletes(mut self, count: i64) -> Self {
+        self.report.metrics.removed_positional_deletes = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_positional_deletes(mut self, count: i64) -> Self {
+        self.report.metrics.total_positional_deletes = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn added_equality_deletes(mut self, count: i64) -> Self {
+        self.report.metrics.added_equality_deletes = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn removed_equality_deletes(mut self, count: i64) -> Self {
+        self.report.metrics.removed_equality_deletes = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn total_equality_deletes(mut self, count: i64) -> Self {
+        self.report.metrics.total_equality_deletes = Some(CounterResult::count(count));
+        self
+    }
+
+    pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.report.metadata = Some(metadata);
+        self
+    }
+
+    pub fn build(self) -> ReportMetricsRequest {
+        ReportMetricsRequest::CommitReport(self.report)
+    }
+}
+
+/// Build the report of a commit from the table metadata returned by the catalog.
+/// Returns `None` if the metadata has no current snapshot.
+pub(crate) fn commit_report(
+    identifier: &Identifier,
+    metadata: &TableMetadata,
+    duration: Duration,
+) -> Option<ReportMetricsRequest> {
+    let snapshot_id = metadata.current_snapshot_id?;
+    let snapshot = metadata
+        .snapshots
+        .as_ref()?
+        .iter()
+        .find(|snapshot| snapshot.snapshot_id == snapshot_id)?;
+    let operation = serde_json::to_value(snapshot.summary.operation).ok()?;
+    Some(
+        CommitReportBuilder::new(
+            &format!("{}.{}", identifier.namespace(), identifier.name()),
+            snapshot_id.into(),
+            snapshot.sequence_number.unwrap_or_default().into(),
+            operation.as_str()?,
+        )
+        .total_duration(duration)
+        .attempts(1)
+        .build(),
+    )
+}
+
+impl RestCatalog {
+    /// Returns a reporter that sends reports to this catalog.
+    pub fn metrics_reporter(&self) -> RestMetricsReporter {
+        RestMetricsReporter::new(self.configuration.clone(), &self.prefix)
+    }
+
+    /// Send a commit report to `reporter` after every successful table update. Reports are sent in the
+    /// background, commits don't wait for them.
+    pub fn with_metrics_reporter(mut self, reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(CatalogMetricsReporter::new(reporter));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use iceberg_rs::catalog::identifier::Identifier;
+
+    use super::{
+        BufferedMetricsReporter, CatalogMetricsReporter, CommitReportBuilder, MetricsReporter,
+        ScanReportBuilder,
+    };
+    use crate::models::ReportMetricsRequest;
+
+    #[derive(Default)]
+    struct CollectingReporter(Mutex<Vec<ReportMetricsRequest>>);
+
+    #[async_trait]
+    impl MetricsReporter for CollectingReporter {
+        async fn report(&self, _identifier: &Identifier, report: ReportMetricsRequest) -> Result<()> {
+            self.0.lock().unwrap().push(report);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_report_builders() {
+        let report = ScanReportBuilder::new("db.table", 1)
+            .planning_duration(Duration::from_millis(12))
+            .result_data_files(3)
+            .skipped_data_manifests(2)
+            .build();
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["report-type"], "scan-report");
+        assert_eq!(json["metrics"]["result-data-files"]["value"], 3);
+        assert_eq!(json["metrics"]["total-planning-duration"]["time-unit"], "milliseconds");
+        assert_eq!(json["metrics"]["total-planning-duration"]["total-duration"], 12);
+        assert!(json["metrics"].get("skipped-data-files").is_none());
+
+        let report = CommitReportBuilder::new("db.table", 2, 5, "append")
+            .attempts(1)
+            .added_records(100)
+            .build();
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["report-type"], "commit-report");
+        assert_eq!(json["sequence-number"], 5);
+        assert_eq!(json["metrics"]["added-records"]["value"], 100);
+        let parsed: ReportMetricsRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[tokio::test]
+    async fn test_buffered_reporter() {
+        let collecting = Arc::new(CollectingReporter::default());
+        let (reporter, worker) = BufferedMetricsReporter::new(collecting.clone(), 1);
+        let identifier = Identifier::parse("db.table").unwrap();
+        for attempts in 1..=3 {
+            let report = CommitReportBuilder::new("db.table", 1, 1, "append")
+                .attempts(attempts)
+                .build();
+            reporter.report(&identifier, report).await.unwrap();
+        }
+        // The worker has not run yet, so the buffer holds one report plus one for the sender
+        assert_eq!(reporter.dropped(), 1);
+        drop(reporter);
+        worker.await;
+        assert_eq!(collecting.0.lock().unwrap().len(), 2);
+    }
+
+    /// Accepts reports but never finishes sending them.
+    #[derive(Default)]
+    struct PendingReporter(Mutex<usize>);
+
+    #[async_trait]
+    impl MetricsReporter for PendingReporter {
+        async fn report(&self, _identifier: &Identifier, _report: ReportMetricsRequest) -> Result<()> {
+            *self.0.lock().unwrap() += 1;
+            futures::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_catalog_reporter_does_not_wait() {
+        let pending = Arc::new(PendingReporter::default());
+        let reporter = CatalogMetricsReporter::new(pending.clone());
+        let identifier = Identifier::parse("db.table").unwrap();
+        let report = CommitReportBuilder::new("db.table", 1, 1, "append").build();
+        reporter.report(&identifier, report.clone()).await;
+        reporter.report(&identifier, report).await;
+        tokio::task::yield_now().await;
+        // The worker is stuck sending the first report
+        assert_eq!(*pending.0.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_catalog_reporter_outside_runtime() {
+        let collecting = Arc::new(CollectingReporter::default());
+        let reporter = CatalogMetricsReporter::new(collecting.clone());
+        let identifier = Identifier::parse("db.table").unwrap();
+        let report = CommitReportBuilder::new("db.table", 1, 1, "append").build();
+        futures::executor::block_on(reporter.report(&identifier, report.clone()));
+        assert!(reporter.worker.lock().unwrap().is_some());
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            reporter.report(&identifier, report).await;
+            tokio::task::yield_now().await;
+        });
+        assert_eq!(collecting.0.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,361 @@
+//! User sessions on top of a catalog, using the OAuth2 token exchange flow.
+//!
+//! The user token is exchanged for a catalog token scoped to the user. The catalog's own token is sent as
+//! actor token. The exchanged token is cached and refreshed shortly before it expires.
+//!
+//! The cache keeps the most recently used sessions. Sessions whose token doesn't expire are dropped once
+//! they haven't been used for an hour.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+
+#[cfg(feature = "metrics")]
+use crate::apis::client_metrics;
+use crate::{
+    apis::{
+        configuration::Configuration,
+        interceptor::{BoxError, Interceptor},
+        o_auth2_api_api,
+    },
+    models::TokenType,
+    secret::Secret,
+};
+
+use super::{
+    builder::{DEFAULT_SCOPE, SCOPE},
+    tables::TableCache,
+    RestCatalog,
+};
+
+const TOKEN_EXCHANGE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+/// Tokens are refreshed when they expire within this duration.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Number of sessions a cache keeps.
+const SESSION_CACHE_CAPACITY: usize = 1000;
+/// Sessions whose token doesn't expire are dropped when they weren't used for this duration.
+const MAX_IDLE: Duration = Duration::from_secs(60 * 60);
+
+/// Cache of user sessions, keyed by the user token. Evicts the least recently used session when it is full.
+#[derive(Clone)]
+pub(crate) struct SessionCache(Arc<Mutex<SessionCacheEntries>>);
+
+struct SessionCacheEntries {
+    capacity: usize,
+    /// Sessions with the time of their last use.
+    sessions: HashMap<Secret, (Arc<SessionToken>, Instant)>,
+}
+
+impl Default for SessionCache {
+    fn default() -> Self {
+        SessionCache::with_capacity(SESSION_CACHE_CAPACITY)
+    }
+}
+
+impl SessionCache {
+    fn with_capacity(capacity: usize) -> Self {
+        SessionCache(Arc::new(Mutex::new(SessionCacheEntries {
+            capacity,
+            sessions: HashMap::new(),
+        })))
+    }
+
+    // Entries are replaced as a whole, a panic while holding the lock can't leave one half written
+    fn entries(&self) -> MutexGuard<'_, SessionCacheEntries> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(&self, user_token: &Secret) -> Option<Arc<SessionToken>> {
+        let now = Instant::now();
+        let mut entries = self.entries();
+        match entries.sessions.get_mut(user_token) {
+            Some((session, used)) if !session.is_stale(*used, now) => {
+                *used = now;
+                Some(Arc::clone(session))
+            }
+            Some(_) => {
+                entries.sessions.remove(user_token);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, user_token: Secret, session: Arc<SessionToken>) {
+        let now = Instant::now();
+        let mut entries = self.entries();
+        entries
+            .sessions
+            .retain(|_, (session, used)| !session.is_stale(*used, now));
+        if !entries.sessions.contains_key(&user_token) && entries.sessions.len() >= entries.capacity
+        {
+            let least_recently_used = entries
+                .sessions
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recently_used) = least_recently_used {
+                entries.sessions.remove(&least_recently_used);
+            }
+        }
+        entries.sessions.insert(user_token, (session, now));
+    }
+}
+
+struct TokenState {
+    token: Secret,
+    expires_at: Option<Instant>,
+}
+
+impl TokenState {
+    fn expires_within(&self, duration: Duration) -> bool {
+        self.expires_at
+            .map(|expires_at| expires_at <= Instant::now() + duration)
+            .unwrap_or(false)
+    }
+}
+
+/// Catalog token of a user session. Sets the `Authorization` header of every request of the session.
+pub(crate) struct SessionToken {
+    /// Configuration of the catalog the session was created from, used to exchange tokens.
+    configuration: Configuration,
+    user_token: Secret,
+    scope: String,
+    state: futures::lock::Mutex<TokenState>,
+    /// Expiry of the current token, readable while the token is refreshed.
+    expires_at: Mutex<Option<Instant>>,
+    /// Tables loaded by the user, they must not be served to other sessions.
+    tables: TableCache,
+}
+
+impl SessionToken {
+    async fn new(configuration: Configuration, user_token: Secret, scope: String) -> Result<Self> {
+        let state = exchange(&configuration, &user_token, &scope).await?;
+        Ok(SessionToken {
+            configuration,
+            user_token,
+            scope,
+            expires_at: Mutex::new(state.expires_at),
+            state: futures::lock::Mutex::new(state),
+            tables: TableCache::default(),
+        })
+    }
+
+    /// Returns the current token, refreshing it if it is about to expire.
+    async fn token(&self) -> Result<Secret> {
+        let mut state = self.state.lock().await;
+        if state.expires_within(REFRESH_MARGIN) {
+            // Refresh the session token itself. If that fails, for example because it already expired,
+            // exchange the user token again.
+            let refreshed = match refresh(&self.configuration, &state.token, &self.scope).await {
+                Ok(refreshed) => {
+                    #[cfg(feature = "metrics")]
+                    client_metrics::record_token_refresh("refresh");
+                    refreshed
+                }
+                Err(_) => {
+                    let exchanged = exchange(&self.configuration, &self.user_token, &self.scope).await?;
+                    #[cfg(feature = "metrics")]
+                    client_metrics::record_token_refresh("exchange");
+                    exchanged
+                }
+            };
+            *self
+                .expires_at
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = refreshed.expires_at;
+            *state = refreshed;
+        }
+        Ok(state.token.clone())
+    }
+
+    /// Returns true if the token expired, or if it doesn't expire and the session was last used before `MAX_IDLE`.
+    fn is_stale(&self, used: Instant, now: Instant) -> bool {
+        match *self
+            .expires_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+        {
+            Some(expires_at) => expires_at <= now,
+            None => used + MAX_IDLE <= now,
+        }
+    }
+}
+
+#[async_trait]
+impl Interceptor for SessionToken {
+    async fn intercept_request(&self, request: &mut reqwest::Request) -> Result<(), BoxError> {
+        let token = self.token().await?;
+        let mut value = HeaderValue::from_str(&format!("Bearer {}", token.expose()))?;
+        value.set_sensitive(true);
+        request.headers_mut().insert(AUTHORIZATION, value);
+        Ok(())
+    }
+}
+
+/// Exchange the user token for a catalog token, with the catalog token as actor.
+async fn exchange(configuration: &Configuration, user_token: &Secret, scope: &str) -> Result<TokenState> {
+    let actor_token = configuration
+        .oauth_access_token
+        .as_ref()
+        .or(configuration.bearer_access_token.as_ref())
+        .ok_or_else(|| anyhow!("Token exchange requires the catalog to have an access token."))?;
+    let response = o_auth2_api_api::get_token(
+        configuration,
+        Some(TOKEN_EXCHANGE_GRANT_TYPE),
+        Some(scope),
+        None,
+        None,
+        Some(TokenType::AccessToken),
+        Some(user_token.expose()),
+        Some(TokenType::AccessToken),
+        Some(actor_token.expose()),
+        Some(TokenType::AccessToken),
+    )
+    .await?;
+    Ok(TokenState {
+        expires_at: expires_at(response.expires_in),
+        token: response.access_token,
+    })
+}
+
+/// Exchange an expiring token for a new one with the same claims. The request is authorized with the expiring token.
+async fn refresh(configuration: &Configuration, token: &Secret, scope: &str) -> Result<TokenState> {
+    let configuration = Configuration {
+        oauth_access_token: Some(token.clone()),
+        bearer_access_token: None,
+        ..configuration.clone()
+    };
+    let response = o_auth2_api_api::get_token(
+        &configuration,
+        Some(TOKEN_EXCHANGE_GRANT_TYPE),
+        Some(scope),
+        None,
+        None,
+        Some(TokenType::AccessToken),
+        Some(token.expose()),
+        Some(TokenType::AccessToken),
+        None,
+        None,
+    )
+    .await?;
+    Ok(TokenState {
+        expires_at: expires_at(response.expires_in),
+        token: response.access_token,
+    })
+}
+
+fn expires_at(expires_in: Option<i32>) -> Option<Instant> {
+    expires_in
+        .filter(|x| *x > 0)
+        .map(|x| Instant::now() + Duration::from_secs(x as u64))
+}
+
+impl RestCatalog {
+    /// Returns a handle of the catalog whose operations run as the user identified by `user_token`.
+    ///
+    /// The user token is exchanged for a user-scoped catalog token, using the token of this catalog as actor.
+    /// Sessions are cached per user token and their tokens are refreshed before they expire. Handles of the same
+    /// session share the tables they loaded.
+    pub async fn with_session(&self, user_token: &str) -> Result<RestCatalog> {
+        let user_token = Secret::from(user_token);
+        let cached = self.sessions.get(&user_token);
+        #[cfg(feature = "metrics")]
+        client_metrics::record_cache_lookup("session", cached.is_some());
+        let session = match cached {
+            Some(session) => session,
+            None => {
+                let scope = self
+                    .properties()
+                    .get(SCOPE)
+                    .cloned()
+                    .unwrap_or_else(|| DEFAULT_SCOPE.to_owned());
+                let session = Arc::new(
+                    SessionToken::new(self.configuration.clone(), user_token.clone(), scope).await?,
+                );
+                self.sessions.insert(user_token, Arc::clone(&session));
+                session
+            }
+        };
+
+        let tables = session.tables.clone();
+        let mut configuration = Configuration {
+            oauth_access_token: None,
+            bearer_access_token: None,
+            ..self.configuration.clone()
+        };
+        configuration.interceptors.push(session);
+        Ok(RestCatalog {
+            name: self.name.clone(),
+            prefix: self.prefix.clone(),
+            configuration,
+            object_store: Arc::clone(&self.object_store),
+            properties: RwLock::new(self.properties()),
+            config: self.config.clone(),
+            sessions: SessionCache::default(),
+            metrics_reporter: self.metrics_reporter.clone(),
+            capabilities: self.capabilities.clone(),
+            head_rejected: Arc::clone(&self.head_rejected),
+            tables,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    use super::{SessionCache, SessionToken, TokenState, MAX_IDLE};
+    use crate::{apis::configuration::Configuration, catalog::tables::TableCache, secret::Secret};
+
+    fn session(expires_at: Option<Instant>) -> Arc<SessionToken> {
+        Arc::new(SessionToken {
+            configuration: Configuration::default(),
+            user_token: Secret::new("user".to_owned()),
+            scope: "catalog".to_owned(),
+            state: futures::lock::Mutex::new(TokenState {
+                token: Secret::new("token".to_owned()),
+                expires_at,
+            }),
+            expires_at: Mutex::new(expires_at),
+            tables: TableCache::default(),
+        })
+    }
+
+    #[test]
+    fn test_evict_sessions() {
+        let sessions = SessionCache::with_capacity(2);
+        let now = Instant::now();
+        let user = |name: &str| Secret::new(name.to_owned());
+
+        sessions.insert(user("expired"), session(Some(now - Duration::from_secs(1))));
+        assert!(sessions.get(&user("expired")).is_none());
+
+        sessions.insert(user("a"), session(Some(now + Duration::from_secs(3600))));
+        sessions.insert(user("b"), session(None));
+        assert!(sessions.get(&user("a")).is_some());
+        sessions.insert(user("c"), session(None));
+        assert!(sessions.get(&user("a")).is_some());
+        assert!(sessions.get(&user("b")).is_none());
+        assert!(sessions.get(&user("c")).is_some());
+
+        let idle = session(None);
+        assert!(!idle.is_stale(now, now + MAX_IDLE - Duration::from_secs(1)));
+        assert!(idle.is_stale(now, now + MAX_IDLE));
+
+        // The expiry stays readable while the token is refreshed
+        let expired = session(Some(now));
+        let _refreshing = expired.state.try_lock().unwrap();
+        assert!(expired.is_stale(now, now));
+    }
+}
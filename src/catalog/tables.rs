@@ -0,0 +1,470 @@
+//! Table metadata as returned by the catalog.
+//!
+//! The [`Catalog`](iceberg_rs::catalog::Catalog) implementation reads the metadata file of a table.
+//! [`RestCatalog::load_table_metadata`] returns the metadata of the load table response instead, which can be
+//! limited to the snapshots referenced by branches and tags. The other snapshots are loaded when they are needed.
+//!
+//! Loaded tables are cached with the `ETag` of their response. A cached table is revalidated by sending the ETag in
+//! `If-None-Match`, the server answers with `304 Not Modified` and no body if the table didn't change. The cache
+//! keeps the most recently used tables.
+//!
+//! Metadata files are immutable, the cache also keeps the parsed metadata files by their location. Loading a table
+//! whose metadata location didn't change doesn't read the object store.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use anyhow::{anyhow, Result};
+use iceberg_rs::catalog::{identifier::Identifier, relation::RelationMetadata};
+use reqwest::StatusCode;
+
+#[cfg(feature = "metrics")]
+use crate::apis::client_metrics;
+use crate::{
+    apis::{
+        catalog_api_api::{
+            self, LoadTableError, LoadTableOptions, LoadTableResponse, SnapshotsMode,
+        },
+        Error,
+    },
+    models::{LoadTableResult, Snapshot, TableMetadata},
+};
+
+use super::{metadata_path, RestCatalog};
+
+/// Number of tables and of metadata files a cache keeps.
+const TABLE_CACHE_CAPACITY: usize = 100;
+
+/// Namespace, table name and the loaded snapshots.
+type TableKey = (String, String, SnapshotsMode);
+
+/// Cache of loaded tables that evicts the least recently used table when it is full.
+#[derive(Debug, Clone)]
+pub(crate) struct TableCache(Arc<Mutex<TableCacheEntries>>);
+
+#[derive(Debug)]
+struct TableCacheEntries {
+    capacity: usize,
+    /// Tables with the tick of their last use.
+    tables: HashMap<TableKey, (LoadedTable, u64)>,
+    /// Parsed metadata files by their location, with the tick of their last use.
+    metadata_files: HashMap<String, (RelationMetadata, u64)>,
+    tick: u64,
+}
+
+impl TableCacheEntries {
+    fn tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// Remove the least recently used entry if `entries` is full and doesn't contain `key`.
+fn make_room<K: Clone + Eq + Hash, V>(
+    entries: &mut HashMap<K, (V, u64)>,
+    key: &K,
+    capacity: usize,
+) {
+    if !entries.contains_key(key) && entries.len() >= capacity {
+        let least_recently_used = entries
+            .iter()
+            .min_by_key(|(_, (_, used))| *used)
+            .map(|(key, _)| key.clone());
+        if let Some(least_recently_used) = least_recently_used {
+            entries.remove(&least_recently_used);
+        }
+    }
+}
+
+impl Default for TableCache {
+    fn default() -> Self {
+        TableCache::with_capacity(TABLE_CACHE_CAPACITY)
+    }
+}
+
+impl TableCache {
+    fn with_capacity(capacity: usize) -> Self {
+        TableCache(Arc::new(Mutex::new(TableCacheEntries {
+            capacity,
+            tables: HashMap::new(),
+            metadata_files: HashMap::new(),
+            tick: 0,
+        })))
+    }
+
+    // Entries are replaced as a whole, a panic while holding the lock can't leave one half written
+    fn entries(&self) -> MutexGuard<'_, TableCacheEntries> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(&self, key: &TableKey) -> Option<LoadedTable> {
+        let mut entries = self.entries();
+        let tick = entries.tick();
+        entries.tables.get_mut(key).map(|(table, used)| {
+            *used = tick;
+            table.clone()
+        })
+    }
+
+    fn insert(&self, key: TableKey, table: LoadedTable) {
+        let mut entries = self.entries();
+        let capacity = entries.capacity;
+        make_room(&mut entries.tables, &key, capacity);
+        let tick = entries.tick();
+        entries.tables.insert(key, (table, tick));
+    }
+
+    fn get_metadata_file(&self, location: &str) -> Option<RelationMetadata> {
+        let mut entries = self.entries();
+        let tick = entries.tick();
+        entries
+            .metadata_files
+            .get_mut(location)
+            .map(|(metadata, used)| {
+                *used = tick;
+                metadata.clone()
+            })
+    }
+
+    fn insert_metadata_file(&self, location: String, metadata: RelationMetadata) {
+        let mut entries = self.entries();
+        let capacity = entries.capacity;
+        make_room(&mut entries.metadata_files, &location, capacity);
+        let tick = entries.tick();
+        entries.metadata_files.insert(location, (metadata, tick));
+    }
+
+    fn remove(&self, key: &TableKey) {
+        self.entries().tables.remove(key);
+    }
+
+    /// Remove all cached versions of a table.
+    fn remove_table(&self, namespace: &str, name: &str) {
+        self.entries()
+            .tables
+            .retain(|(x, y, _), _| x != namespace || y != name);
+    }
+}
+
+/// A table loaded from the catalog.
+#[derive(Debug, Clone)]
+pub struct LoadedTable {
+    identifier: Identifier,
+    result: LoadTableResult,
+    snapshots: SnapshotsMode,
+}
+
+impl LoadedTable {
+    pub fn identifier(&self) -> &Identifier {
+        &self.identifier
+    }
+
+    pub fn result(&self) -> &LoadTableResult {
+        &self.result
+    }
+
+    pub fn metadata(&self) -> &TableMetadata {
+        &self.result.metadata
+    }
+
+    pub fn metadata_location(&self) -> Option<&str> {
+        self.result.metadata_location.as_deref()
+    }
+
+    /// Returns true if the metadata contains all snapshots of the table, not only the referenced ones.
+    pub fn has_all_snapshots(&self) -> bool {
+        self.snapshots == SnapshotsMode::All
+    }
+
+    /// All snapshots of the table. If only the referenced snapshots were loaded, the table is loaded again
+    /// with all of them, so the metadata may be more recent afterwards.
+    pub async fn snapshots(&mut self, catalog: &RestCatalog) -> Result<&[Snapshot]> {
+        if !self.has_all_snapshots() {
+            *self = catalog
+                .load_table_metadata(&self.identifier, SnapshotsMode::All)
+                .await?;
+        }
+        Ok(self
+            .result
+            .metadata
+            .snapshots
+            .as_deref()
+            .unwrap_or_default())
+    }
+}
+
+impl RestCatalog {
+    /// Load the metadata of a table with `snapshots` as returned by the catalog, without reading its
+    /// metadata file.
+    ///
+    /// A cached version of the table is returned if the server reports it unchanged. Conditional loads with
+    /// an ETag of the caller are made with [`catalog_api_api::load_table`].
+    pub async fn load_table_metadata(
+        &self,
+        identifier: &Identifier,
+        snapshots: SnapshotsMode,
+    ) -> Result<LoadedTable> {
+        Ok(self.load_cached_table(identifier, snapshots).await?)
+    }
+
+    pub(crate) async fn load_cached_table(
+        &self,
+        identifier: &Identifier,
+        snapshots: SnapshotsMode,
+    ) -> Result<LoadedTable, Error<LoadTableError>> {
+        let key = (
+            identifier.namespace().to_string(),
+            identifier.name().to_owned(),
+            snapshots,
+        );
+        let cached = self.tables.get(&key);
+        let options = LoadTableOptions {
+            snapshots: Some(snapshots),
+            if_none_match: cached.as_ref().and_then(|x| x.result.etag.clone()),
+        };
+        let response = catalog_api_api::load_table(
+            &self.configuration,
+            &self.prefix,
+            &identifier.namespace().to_string(),
+            identifier.name(),
+            &options,
+        )
+        .await;
+        #[cfg(feature = "metrics")]
+        client_metrics::record_cache_lookup(
+            "table",
+            matches!(response, Ok(LoadTableResponse::NotModified)),
+        );
+        match response {
+            Ok(LoadTableResponse::Loaded(result)) => {
+                let table = LoadedTable {
+                    identifier: identifier.clone(),
+                    result,
+                    snapshots: key.2,
+                };
+                if table.result.etag.is_some() {
+                    self.tables.insert(key, table.clone());
+                } else {
+                    self.tables.remove(&key);
+                }
+                Ok(table)
+            }
+            // If-None-Match is only sent with the ETag of a cached version
+            Ok(LoadTableResponse::NotModified) => Ok(cached.expect("Revalidated table is cached")),
+            Err(err) => {
+                if matches!(&err, Error::ResponseError(content) if content.status == StatusCode::NOT_FOUND)
+                {
+                    self.tables.remove(&key);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Read and parse the metadata file at `location`, unless it is cached.
+    pub(crate) async fn read_relation_metadata(&self, location: &str) -> Result<RelationMetadata> {
+        if let Some(metadata) = self.tables.get_metadata_file(location) {
+            return Ok(metadata);
+        }
+        let bytes = self.read_metadata_file(&metadata_path(location)?).await?;
+        let metadata: RelationMetadata =
+            serde_json::from_slice(&bytes).map_err(|err| anyhow!(err.to_string()))?;
+        self.tables
+            .insert_metadata_file(location.to_owned(), metadata.clone());
+        Ok(metadata)
+    }
+
+    /// Remove all cached versions of a table.
+    pub(crate) fn evict_table(&self, identifier: &Identifier) {
+        self.tables
+            .remove_table(&identifier.namespace().to_string(), identifier.name());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use iceberg_rs::{
+        catalog::{identifier::Identifier, relation::RelationMetadata},
+        object_store::{memory::InMemory, ObjectStore},
+    };
+
+    use super::{LoadedTable, TableCache};
+    use crate::{
+        apis::{
+            catalog_api_api::{self, LoadTableOptions, LoadTableResponse, SnapshotsMode},
+            configuration::Configuration,
+        },
+        catalog::{metadata_path, RestCatalog},
+        models::LoadTableResult,
+        test_util::{empty_response, ServerStub},
+    };
+
+    fn catalog(server: &ServerStub) -> RestCatalog {
+        RestCatalog::new(
+            "my_catalog".to_owned(),
+            Configuration {
+                base_path: server.uri.clone(),
+                ..Default::default()
+            },
+            Arc::new(InMemory::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_load_table_options() {
+        let server = ServerStub::start(vec![empty_response("304 Not Modified")]).await;
+        let catalog = catalog(&server);
+        let options = LoadTableOptions::new()
+            .snapshots(SnapshotsMode::Refs)
+            .if_none_match("\"1a2b\"".to_owned());
+        let response = catalog_api_api::load_table(
+            &catalog.configuration,
+            &catalog.prefix,
+            "sales",
+            "orders",
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response, LoadTableResponse::NotModified);
+
+        let requests = server.requests().await;
+        assert_eq!(
+            requests[0].target,
+            "/v1/my_catalog/namespaces/sales/tables/orders?snapshots=refs"
+        );
+        assert_eq!(requests[0].header("if-none-match"), Some("\"1a2b\""));
+    }
+
+    #[tokio::test]
+    async fn test_revalidate_cached_table() {
+        let body = r#"{"metadata-location":"s3://bucket/sales/orders/metadata/00001.metadata.json","metadata":{"format-version":2,"table-uuid":"9c12d441"}}"#;
+        let server = ServerStub::start(vec![
+            format!(
+                "HTTP/1.1 200 OK\r\netag: \"1a2b\"\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            ),
+            "HTTP/1.1 304 Not Modified\r\netag: \"1a2b\"\r\ncontent-length: 0\r\n\r\n".to_owned(),
+        ])
+        .await;
+        let catalog = catalog(&server);
+        let identifier = Identifier::parse("sales.orders").unwrap();
+        let loaded = catalog
+            .load_table_metadata(&identifier, SnapshotsMode::All)
+            .await
+            .unwrap();
+        assert_eq!(loaded.result().etag.as_deref(), Some("\"1a2b\""));
+        let revalidated = catalog
+            .load_table_metadata(&identifier, SnapshotsMode::All)
+            .await
+            .unwrap();
+        assert_eq!(revalidated.result(), loaded.result());
+
+        let requests = server.requests().await;
+        assert_eq!(requests[0].header("if-none-match"), None);
+        assert_eq!(requests[1].header("if-none-match"), Some("\"1a2b\""));
+    }
+
+    #[tokio::test]
+    async fn test_metadata_file_read_once() {
+        let location = "s3://bucket/sales/orders/metadata/00001.metadata.json";
+        let body = format!(
+            r#"{{"metadata-location":"{}","metadata":{{"format-version":2,"table-uuid":"fb072c92-a02b-11e9-ae9c-1bb7bc9eca94"}}}}"#,
+            location
+        );
+        let server = ServerStub::start(vec![
+            format!(
+                "HTTP/1.1 200 OK\r\netag: \"1a2b\"\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            ),
+            "HTTP/1.1 304 Not Modified\r\netag: \"1a2b\"\r\ncontent-length: 0\r\n\r\n".to_owned(),
+        ])
+        .await;
+        let object_store = Arc::new(InMemory::new());
+        let path = metadata_path(location).unwrap();
+        object_store
+            .put(&path, METADATA.as_bytes().to_vec().into())
+            .await
+            .unwrap();
+        let catalog = RestCatalog::new(
+            "my_catalog".to_owned(),
+            Configuration {
+                base_path: server.uri.clone(),
+                ..Default::default()
+            },
+            object_store.clone(),
+        );
+        let identifier = Identifier::parse("sales.orders").unwrap();
+
+        let loaded = catalog
+            .load_cached_table(&identifier, SnapshotsMode::Refs)
+            .await
+            .unwrap();
+        let metadata = catalog
+            .read_relation_metadata(loaded.metadata_location().unwrap())
+            .await
+            .unwrap();
+        assert!(matches!(metadata, RelationMetadata::Table(_)));
+
+        // The second load must not read the object store
+        object_store.delete(&path).await.unwrap();
+        let revalidated = catalog
+            .load_cached_table(&identifier, SnapshotsMode::Refs)
+            .await
+            .unwrap();
+        assert_eq!(revalidated.metadata_location(), Some(location));
+        let metadata = catalog
+            .read_relation_metadata(revalidated.metadata_location().unwrap())
+            .await
+            .unwrap();
+        assert!(matches!(metadata, RelationMetadata::Table(_)));
+        assert_eq!(server.requests().await.len(), 2);
+    }
+
+    const METADATA: &str = r#"{
+        "format-version": 2,
+        "table-uuid": "fb072c92-a02b-11e9-ae9c-1bb7bc9eca94",
+        "location": "s3://bucket/sales/orders",
+        "last-sequence-number": 0,
+        "last-updated-ms": 1662532818843,
+        "last-column-id": 1,
+        "schemas": [{"schema-id": 0, "type": "struct",
+                     "fields": [{"id": 1, "name": "id", "required": true, "type": "long"}]}],
+        "current-schema-id": 0,
+        "partition-specs": [{"spec-id": 0, "fields": []}],
+        "default-spec-id": 0,
+        "last-partition-id": 999,
+        "properties": {},
+        "snapshot-log": [],
+        "metadata-log": [],
+        "sort-orders": [{"order-id": 0, "fields": []}],
+        "default-sort-order-id": 0,
+        "refs": {}
+    }"#;
+
+    #[test]
+    fn test_evict_least_recently_used() {
+        let cache = TableCache::with_capacity(2);
+        let key = |name: &str| ("sales".to_owned(), name.to_owned(), SnapshotsMode::All);
+        let table = |name: &str| LoadedTable {
+            identifier: Identifier::parse(&format!("sales.{}", name)).unwrap(),
+            result: LoadTableResult::default(),
+            snapshots: SnapshotsMode::All,
+        };
+        cache.insert(key("orders"), table("orders"));
+        cache.insert(key("customers"), table("customers"));
+        assert!(cache.get(&key("orders")).is_some());
+        cache.insert(key("products"), table("products"));
+
+        assert!(cache.get(&key("customers")).is_none());
+        assert!(cache.get(&key("orders")).is_some());
+        assert!(cache.get(&key("products")).is_some());
+    }
+}
@@ -0,0 +1,364 @@
+//! Atomic commits of changes to several tables.
+//!
+//! A [`MultiTableTransaction`] collects the changes of every table and [`RestCatalog::commit_transaction`]
+//! sends them in a single request. The catalog applies all of them or none.
+//!
+//! Changes of tables are committed with the requirements that each table still has the uuid and the snapshot
+//! of its main branch it was loaded with, so that concurrent changes fail the whole transaction.
+
+use std::fmt;
+
+use anyhow::Result;
+use iceberg_rs::{catalog::identifier::Identifier, table::Table};
+
+use crate::{
+    apis::{catalog_api_api, Error},
+    models::{
+        table_requirement::Requirement, CommitTableRequest, CommitTransactionRequest,
+        TableMetadata, TableRequirement,
+    },
+};
+
+use super::{
+    capabilities::{self, Unsupported},
+    table_identifier,
+    tables::LoadedTable,
+    updates::table_updates,
+    RestCatalog,
+};
+
+pub(super) const MAIN_BRANCH: &str = "main";
+
+/// Changes of several tables that are committed together.
+#[derive(Debug, Clone, Default)]
+pub struct MultiTableTransaction {
+    table_changes: Vec<CommitTableRequest>,
+}
+
+impl MultiTableTransaction {
+    pub fn new() -> Self {
+        MultiTableTransaction::default()
+    }
+
+    /// Change a table loaded with [`RestCatalog::load_table_metadata`] to the new `metadata`, the change that
+    /// [`Catalog::update_table`](iceberg_rs::catalog::Catalog::update_table) commits for a single table.
+    /// Load the table with all snapshots, snapshots missing from the loaded metadata would be added again.
+    pub fn update_table(self, table: &LoadedTable, metadata: &TableMetadata) -> Self {
+        self.commit_table(
+            table.identifier(),
+            CommitTableRequest::new(
+                requirements(table.metadata()),
+                table_updates(table.metadata(), metadata),
+            ),
+        )
+    }
+
+    /// Change an iceberg-rs table as it was loaded, `base`, to `table` with the uncommitted changes applied.
+    /// The requirements are built from `base`.
+    pub fn update_iceberg_table(self, base: &Table, table: &Table) -> Result<Self> {
+        let base: TableMetadata = serde_json::from_value(serde_json::to_value(base.metadata())?)?;
+        let metadata: TableMetadata =
+            serde_json::from_value(serde_json::to_value(table.metadata())?)?;
+        Ok(self.commit_table(
+            table.identifier(),
+            CommitTableRequest::new(requirements(&base), table_updates(&base, &metadata)),
+        ))
+    }
+
+    /// Add the requirements and updates of a table. The identifier of the request is set to `identifier`.
+    pub fn commit_table(
+        mut self,
+        identifier: &Identifier,
+        mut request: CommitTableRequest,
+    ) -> Self {
+        request.identifier = Some(Box::new(table_identifier(identifier)));
+        self.table_changes.push(request);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.table_changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table_changes.is_empty()
+    }
+}
+
+/// Requirements that the table still has the uuid of `metadata` and its main branch still points to the same
+/// snapshot, or doesn't exist if it didn't.
+fn requirements(metadata: &TableMetadata) -> Vec<TableRequirement> {
+    let mut table_uuid = TableRequirement::new(Requirement::TableUuid);
+    table_uuid.uuid = Some(metadata.table_uuid.clone());
+    let mut main = TableRequirement::new(Requirement::RefSnapshotId);
+    main.r#ref = Some(MAIN_BRANCH.to_owned());
+    main.snapshot_id = metadata
+        .refs
+        .as_ref()
+        .and_then(|refs| refs.get(MAIN_BRANCH))
+        .map(|reference| reference.snapshot_id)
+        .or_else(|| metadata.current_snapshot_id.map(i64::from));
+    vec![table_uuid, main]
+}
+
+impl From<MultiTableTransaction> for CommitTransactionRequest {
+    fn from(transaction: MultiTableTransaction) -> Self {
+        CommitTransactionRequest::new(transaction.table_changes)
+    }
+}
+
+/// Failure to commit a [`MultiTableTransaction`].
+#[derive(Debug)]
+pub enum TransactionError {
+    /// The server doesn't support multi-table transactions.
+    Unsupported(Unsupported),
+    /// A requirement of a table failed, for example because it was changed concurrently. No table was changed.
+    Conflict {
+        message: String,
+        request_id: Option<String>,
+    },
+    /// A table of the transaction doesn't exist. No table was changed.
+    NotFound {
+        message: String,
+        request_id: Option<String>,
+    },
+    /// The catalog failed while committing and the changes may or may not have been applied.
+    /// Reload the tables before retrying.
+    CommitStateUnknown {
+        status: reqwest::StatusCode,
+        message: String,
+        request_id: Option<String>,
+    },
+    /// The transaction was rejected for another reason or could not be sent.
+    Failed(Error<catalog_api_api::CommitTransactionError>),
+}
+
+impl TransactionError {
+    /// Returns true if the transaction may be retried after reloading the tables.
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, TransactionError::Conflict { .. })
+    }
+}
+
+impl From<Error<catalog_api_api::CommitTransactionError>> for TransactionError {
+    fn from(err: Error<catalog_api_api::CommitTransactionError>) -> Self {
+        let content = match err {
+            Error::ResponseError(content) => content,
+            err => return TransactionError::Failed(err),
+        };
+        let message = error_message(&content.content);
+        match content.status.as_u16() {
+            409 => TransactionError::Conflict {
+                message,
+                request_id: content.request_id,
+            },
+            404 => TransactionError::NotFound {
+                message,
+                request_id: content.request_id,
+            },
+            500 | 502 | 504 => TransactionError::CommitStateUnknown {
+                status: content.status,
+                message,
+                request_id: content.request_id,
+            },
+            _ => TransactionError::Failed(Error::ResponseError(content)),
+        }
+    }
+}
+
+/// Message of an error response, with or without the `error` envelope.
+fn error_message(content: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(content)
+        .ok()
+        .and_then(|value| {
+            value
+                .get("error")
+                .unwrap_or(&value)
+                .get("message")
+                .and_then(|x| x.as_str())
+                .map(ToOwned::to_owned)
+        })
+        .unwrap_or_else(|| content.to_owned())
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, message, request_id) = match self {
+            TransactionError::Conflict {
+                message,
+                request_id,
+            } => ("conflict", message, request_id),
+            TransactionError::NotFound {
+                message,
+                request_id,
+            } => ("table not found", message, request_id),
+            TransactionError::CommitStateUnknown {
+                message,
+                request_id,
+                ..
+            } => ("commit state unknown", message, request_id),
+            TransactionError::Unsupported(err) => return write!(f, "transaction failed: {}", err),
+            TransactionError::Failed(err) => return write!(f, "transaction failed: {}", err),
+        };
+        write!(f, "transaction failed, {}: {}", kind, message)?;
+        if let Some(request_id) = request_id {
+            write!(f, " (request id {})", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TransactionError::Unsupported(err) => Some(err),
+            TransactionError::Failed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl RestCatalog {
+    /// Commit the changes of all tables of the transaction atomically.
+    pub async fn commit_transaction(
+        &self,
+        transaction: MultiTableTransaction,
+    ) -> Result<(), TransactionError> {
+        self.capabilities
+            .check(capabilities::COMMIT_TRANSACTION)
+            .map_err(TransactionError::Unsupported)?;
+        catalog_api_api::commit_transaction(&self.configuration, &self.prefix, transaction.into())
+            .await
+            .map_err(TransactionError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use iceberg_rs::{catalog::identifier::Identifier, object_store::memory::InMemory};
+
+    use super::{MultiTableTransaction, TransactionError};
+    use crate::{
+        apis::{
+            catalog_api_api::SnapshotsMode, configuration::Configuration, Error, ResponseContent,
+        },
+        catalog::RestCatalog,
+        models::{
+            snapshot_reference::RHashType, snapshot_summary::Operation, Snapshot,
+            SnapshotReference, SnapshotSummary,
+        },
+        test_util::{empty_response, json_response, ServerStub},
+    };
+
+    #[tokio::test]
+    async fn test_transaction_request() {
+        let server = ServerStub::start(vec![
+            json_response(
+                "200 OK",
+                r#"{"metadata-location":"s3://bucket/sales/orders/metadata/00001.metadata.json",
+                    "metadata":{"format-version":2,"table-uuid":"9c12d441","current-snapshot-id":3,
+                                "refs":{"main":{"type":"branch","snapshot-id":3}}}}"#,
+            ),
+            json_response(
+                "200 OK",
+                r#"{"metadata-location":"s3://bucket/sales/customers/metadata/00004.metadata.json",
+                    "metadata":{"format-version":2,"table-uuid":"5f6a7b8c"}}"#,
+            ),
+            empty_response("204 No Content"),
+        ])
+        .await;
+        let catalog = RestCatalog::new(
+            "my_catalog".to_owned(),
+            Configuration {
+                base_path: server.uri.clone(),
+                ..Default::default()
+            },
+            Arc::new(InMemory::new()),
+        );
+        let orders = catalog
+            .load_table_metadata(
+                &Identifier::parse("sales.orders").unwrap(),
+                SnapshotsMode::All,
+            )
+            .await
+            .unwrap();
+        let customers = catalog
+            .load_table_metadata(
+                &Identifier::parse("sales.customers").unwrap(),
+                SnapshotsMode::All,
+            )
+            .await
+            .unwrap();
+        let mut orders_metadata = orders.metadata().clone();
+        orders_metadata.current_snapshot_id = Some(4);
+        orders_metadata.snapshots = Some(vec![Snapshot::new(
+            4,
+            2000,
+            "s3://bucket/sales/orders/metadata/snap-4.avro".to_owned(),
+            SnapshotSummary::new(Operation::Append),
+        )]);
+        orders_metadata.refs = Some(HashMap::from([(
+            "main".to_owned(),
+            SnapshotReference::new(RHashType::Branch, 4),
+        )]));
+        let mut customers_metadata = customers.metadata().clone();
+        customers_metadata.properties =
+            Some(HashMap::from([("owner".to_owned(), "sales".to_owned())]));
+        let transaction = MultiTableTransaction::new()
+            .update_table(&orders, &orders_metadata)
+            .update_table(&customers, &customers_metadata);
+        catalog.commit_transaction(transaction).await.unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(requests[2].target, "/v1/my_catalog/transactions/commit");
+        let json: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+        let orders = &json["table-changes"][0];
+        assert_eq!(orders["identifier"]["namespace"][0], "sales");
+        assert_eq!(
+            orders["requirements"],
+            serde_json::json!([
+                {"requirement": "assert-table-uuid", "uuid": "9c12d441"},
+                {"requirement": "assert-ref-snapshot-id", "ref": "main", "snapshot-id": 3}
+            ])
+        );
+        assert_eq!(
+            orders["updates"],
+            serde_json::json!([
+                {"action": "add-snapshot", "snapshot": {"snapshot-id": 4, "timestamp-ms": 2000,
+                 "manifest-list": "s3://bucket/sales/orders/metadata/snap-4.avro",
+                 "summary": {"operation": "append"}}},
+                {"action": "set-snapshot-ref", "ref-name": "main", "type": "branch", "snapshot-id": 4}
+            ])
+        );
+        let customers = &json["table-changes"][1];
+        assert_eq!(customers["identifier"]["name"], "customers");
+        // The main branch must not exist yet
+        assert_eq!(
+            customers["requirements"][1],
+            serde_json::json!({"requirement": "assert-ref-snapshot-id", "ref": "main"})
+        );
+        assert_eq!(
+            customers["updates"],
+            serde_json::json!([
+                {"action": "set-properties", "updates": {"owner": "sales"}}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_transaction_error() {
+        let error = TransactionError::from(Error::ResponseError(ResponseContent {
+            status: reqwest::StatusCode::CONFLICT,
+            content: r#"{"error":{"message":"Requirement failed: branch main has changed","type":"CommitFailedException","code":409}}"#.to_owned(),
+            entity: None,
+            request_id: Some("c0ffee".to_owned()),
+        }));
+        assert!(error.is_conflict());
+        assert_eq!(
+            error.to_string(),
+            "transaction failed, conflict: Requirement failed: branch main has changed (request id c0ffee)"
+        );
+    }
+}
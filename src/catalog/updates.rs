@@ -0,0 +1,220 @@
+//! Updates that commit a new metadata file of a table.
+//!
+//! The catalog doesn't accept a metadata file, commits list the changes to the metadata the table was loaded
+//! with. They are derived by comparing that metadata with the new one.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{
+    snapshot_reference,
+    table_update::{self, Action},
+    SnapshotReference, TableMetadata, TableUpdate,
+};
+
+use super::transaction::MAIN_BRANCH;
+
+/// Updates that change `base` into `metadata`.
+pub(crate) fn table_updates(base: &TableMetadata, metadata: &TableMetadata) -> Vec<TableUpdate> {
+    let mut updates = Vec::new();
+
+    if metadata.format_version != base.format_version {
+        let mut update = TableUpdate::new(Action::UpgradeFormatVersion);
+        update.format_version = Some(metadata.format_version);
+        updates.push(update);
+    }
+
+    let schema_ids = ids(&base.schemas, |schema| schema.schema_id);
+    for schema in metadata.schemas.iter().flatten() {
+        if !schema_ids.contains(&schema.schema_id) {
+            let mut update = TableUpdate::new(Action::AddSchema);
+            update.schema = Some(Box::new(schema.clone()));
+            updates.push(update);
+        }
+    }
+    if metadata.current_schema_id != base.current_schema_id {
+        if let Some(schema_id) = metadata.current_schema_id {
+            let mut update = TableUpdate::new(Action::SetCurrentSchema);
+            update.schema_id = Some(schema_id);
+            updates.push(update);
+        }
+    }
+
+    let spec_ids = ids(&base.partition_specs, |spec| spec.spec_id);
+    for spec in metadata.partition_specs.iter().flatten() {
+        if !spec_ids.contains(&spec.spec_id) {
+            let mut update = TableUpdate::new(Action::AddSpec);
+            update.spec = Some(Box::new(spec.clone()));
+            updates.push(update);
+        }
+    }
+    if metadata.default_spec_id != base.default_spec_id {
+        if let Some(spec_id) = metadata.default_spec_id {
+            let mut update = TableUpdate::new(Action::SetDefaultSpec);
+            update.spec_id = Some(spec_id);
+            updates.push(update);
+        }
+    }
+
+    let order_ids = ids(&base.sort_orders, |order| order.order_id);
+    for order in metadata.sort_orders.iter().flatten() {
+        if !order_ids.contains(&order.order_id) {
+            let mut update = TableUpdate::new(Action::AddSortOrder);
+            update.sort_order = Some(Box::new(order.clone()));
+            updates.push(update);
+        }
+    }
+    if metadata.default_sort_order_id != base.default_sort_order_id {
+        if let Some(order_id) = metadata.default_sort_order_id {
+            let mut update = TableUpdate::new(Action::SetDefaultSortOrder);
+            update.sort_order_id = Some(order_id);
+            updates.push(update);
+        }
+    }
+
+    let base_snapshot_ids = ids(&base.snapshots, |snapshot| snapshot.snapshot_id);
+    let snapshot_ids = ids(&metadata.snapshots, |snapshot| snapshot.snapshot_id);
+    for snapshot in metadata.snapshots.iter().flatten() {
+        if !base_snapshot_ids.contains(&snapshot.snapshot_id) {
+            let mut update = TableUpdate::new(Action::AddSnapshot);
+            update.snapshot = Some(Box::new(snapshot.clone()));
+            updates.push(update);
+        }
+    }
+
+    let base_refs = refs(base);
+    let refs = refs(metadata);
+    for (name, reference) in &refs {
+        if base_refs.get(name) != Some(reference) {
+            let mut update = TableUpdate::new(Action::SetSnapshotRef);
+            update.ref_name = Some(name.clone());
+            update.r#type = Some(match reference.r#type {
+                snapshot_reference::RHashType::Tag => table_update::RHashType::Tag,
+                snapshot_reference::RHashType::Branch => table_update::RHashType::Branch,
+            });
+            update.snapshot_id = Some(reference.snapshot_id);
+            update.max_ref_age_ms = reference.max_ref_age_ms;
+            update.max_snapshot_age_ms = reference.max_snapshot_age_ms;
+            update.min_snapshots_to_keep = reference.min_snapshots_to_keep;
+            updates.push(update);
+        }
+    }
+    for name in base_refs.keys() {
+        if !refs.contains_key(name) {
+            let mut update = TableUpdate::new(Action::RemoveSnapshotRef);
+            update.ref_name = Some(name.clone());
+            updates.push(update);
+        }
+    }
+
+    let removed_snapshot_ids: Vec<i64> = base
+        .snapshots
+        .iter()
+        .flatten()
+        .filter(|snapshot| !snapshot_ids.contains(&snapshot.snapshot_id))
+        .map(|snapshot| snapshot.snapshot_id.into())
+        .collect();
+    if !removed_snapshot_ids.is_empty() {
+        let mut update = TableUpdate::new(Action::RemoveSnapshots);
+        update.snapshot_ids = Some(removed_snapshot_ids);
+        updates.push(update);
+    }
+
+    if metadata.location != base.location {
+        if let Some(ref location) = metadata.location {
+            let mut update = TableUpdate::new(Action::SetLocation);
+            update.location = Some(location.clone());
+            updates.push(update);
+        }
+    }
+
+    let empty = HashMap::new();
+    let base_properties = base.properties.as_ref().unwrap_or(&empty);
+    let properties = metadata.properties.as_ref().unwrap_or(&empty);
+    let changed: HashMap<String, String> = properties
+        .iter()
+        .filter(|(key, value)| base_properties.get(*key) != Some(*value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    if !changed.is_empty() {
+        let mut update = TableUpdate::new(Action::SetProperties);
+        update.updates = Some(changed);
+        updates.push(update);
+    }
+    let mut removed: Vec<String> = base_properties
+        .keys()
+        .filter(|key| !properties.contains_key(*key))
+        .cloned()
+        .collect();
+    if !removed.is_empty() {
+        removed.sort();
+        let mut update = TableUpdate::new(Action::RemoveProperties);
+        update.removals = Some(removed);
+        updates.push(update);
+    }
+
+    updates
+}
+
+fn ids<T, I: Eq + std::hash::Hash>(items: &Option<Vec<T>>, id: impl Fn(&T) -> I) -> HashSet<I> {
+    items.iter().flatten().map(id).collect()
+}
+
+/// References of the table. Tables without references, like those of format version 1, have a main branch
+/// at their current snapshot.
+fn refs(metadata: &TableMetadata) -> HashMap<String, SnapshotReference> {
+    match (&metadata.refs, metadata.current_snapshot_id) {
+        (Some(refs), _) => refs.clone(),
+        (None, Some(snapshot_id)) => HashMap::from([(
+            MAIN_BRANCH.to_owned(),
+            SnapshotReference::new(snapshot_reference::RHashType::Branch, snapshot_id.into()),
+        )]),
+        (None, None) => HashMap::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::table_updates;
+    use crate::models::TableMetadata;
+
+    #[test]
+    fn test_table_updates() {
+        let base: TableMetadata = serde_json::from_value(serde_json::json!({
+            "format-version": 1,
+            "table-uuid": "9c12d441",
+            "location": "s3://bucket/sales/orders",
+            "properties": {"owner": "sales", "write.format.default": "parquet"},
+            "current-snapshot-id": 1,
+            "snapshots": [{"snapshot-id": 1, "timestamp-ms": 1000, "manifest-list": "s3://bucket/snap-1.avro",
+                           "summary": {"operation": "append"}}]
+        }))
+        .unwrap();
+        let metadata: TableMetadata = serde_json::from_value(serde_json::json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441",
+            "location": "s3://bucket/sales/orders",
+            "properties": {"owner": "finance"},
+            "current-snapshot-id": 2,
+            "snapshots": [{"snapshot-id": 2, "parent-snapshot-id": 1, "timestamp-ms": 2000,
+                           "manifest-list": "s3://bucket/snap-2.avro", "summary": {"operation": "overwrite"}}],
+            "refs": {"main": {"type": "branch", "snapshot-id": 2}}
+        }))
+        .unwrap();
+
+        let updates = serde_json::to_value(table_updates(&base, &metadata)).unwrap();
+        assert_eq!(
+            updates,
+            serde_json::json!([
+                {"action": "upgrade-format-version", "format-version": 2},
+                {"action": "add-snapshot", "snapshot": {"snapshot-id": 2, "parent-snapshot-id": 1,
+                 "timestamp-ms": 2000, "manifest-list": "s3://bucket/snap-2.avro",
+                 "summary": {"operation": "overwrite"}}},
+                {"action": "set-snapshot-ref", "ref-name": "main", "type": "branch", "snapshot-id": 2},
+                {"action": "remove-snapshots", "snapshot-ids": [1]},
+                {"action": "set-properties", "updates": {"owner": "finance"}},
+                {"action": "remove-properties", "removals": ["write.format.default"]}
+            ])
+        );
+        assert!(table_updates(&metadata, &metadata).is_empty());
+    }
+}
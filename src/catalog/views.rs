@@ -0,0 +1,269 @@
+//! Views of the catalog.
+//!
+//! Views live under their own endpoints. The [`Catalog`] implementation falls back to them whenever no table
+//! with the identifier exists, so iceberg-rs views are loaded, registered, replaced and dropped like tables.
+//! iceberg-rs writes the metadata file of a view itself, the catalog is sent its current version and schema.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use iceberg_rs::{
+    catalog::{identifier::Identifier, namespace::Namespace, relation::RelationMetadata, Catalog},
+    view::View,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    apis::{catalog_api_api, Error},
+    models::{
+        self, CommitViewRequest, CreateViewRequest, ViewMetadata, ViewRequirement, ViewUpdate,
+    },
+};
+
+use super::{capabilities, metadata_path, table_identifier, RestCatalog};
+
+impl RestCatalog {
+    /// Lists all views in the given namespace, following the pages of paginated listings.
+    pub async fn list_views(&self, namespace: &Namespace) -> Result<Vec<Identifier>> {
+        self.capabilities.check(capabilities::LIST_VIEWS)?;
+        let namespace = namespace.to_string();
+        let mut identifiers = Vec::new();
+        let mut page_token = Some(String::new());
+        while let Some(token) = page_token {
+            let page = catalog_api_api::list_views(
+                &self.configuration,
+                &self.prefix,
+                &namespace,
+                Some(&token),
+                None,
+            )
+            .await?;
+            identifiers.extend(page.identifiers.unwrap_or_default());
+            page_token = self.next_page_token(page.next_page_token);
+        }
+        identifiers
+            .into_iter()
+            .map(|x| {
+                let mut vec = x.namespace;
+                vec.push(x.name);
+                Identifier::try_new(&vec)
+            })
+            .collect::<Result<Vec<Identifier>>>()
+    }
+
+    /// Check if a view exists.
+    pub async fn view_exists(&self, identifier: &Identifier) -> Result<bool> {
+        self.capabilities.check(capabilities::VIEW_EXISTS)?;
+        match catalog_api_api::view_exists(
+            &self.configuration,
+            &self.prefix,
+            &identifier.namespace().to_string(),
+            identifier.name(),
+        )
+        .await
+        {
+            Ok(()) => Ok(true),
+            Err(Error::ResponseError(content)) if content.status == StatusCode::NOT_FOUND => {
+                Ok(false)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Load a view.
+    pub async fn load_view(self: Arc<Self>, identifier: &Identifier) -> Result<View> {
+        self.capabilities.check(capabilities::LOAD_VIEW)?;
+        let result = catalog_api_api::load_view(
+            &self.configuration,
+            &self.prefix,
+            &identifier.namespace().to_string(),
+            identifier.name(),
+        )
+        .await?;
+        let path = metadata_path(&result.metadata_location)?;
+        let bytes = self.read_metadata_file(&path).await?;
+        let metadata: RelationMetadata =
+            serde_json::from_slice(&bytes).map_err(|err| anyhow!(err.to_string()))?;
+        let catalog: Arc<dyn Catalog> = self;
+        match metadata {
+            RelationMetadata::View(metadata) => {
+                View::new_metastore_view(identifier.clone(), catalog, metadata, &path.to_string())
+                    .await
+            }
+            RelationMetadata::Table(_) => Err(anyhow!(
+                "Metadata of view {}.{} describes a table.",
+                identifier.namespace(),
+                identifier.name()
+            )),
+        }
+    }
+
+    /// Drop a view. Its metadata files are not deleted.
+    pub async fn drop_view(&self, identifier: &Identifier) -> Result<()> {
+        self.capabilities.check(capabilities::DROP_VIEW)?;
+        catalog_api_api::drop_view(
+            &self.configuration,
+            &self.prefix,
+            &identifier.namespace().to_string(),
+            identifier.name(),
+        )
+        .await
+        .map_err(anyhow::Error::msg)
+    }
+
+    /// Rename a view. Moving a view to another namespace may not be supported by the server.
+    pub async fn rename_view(&self, source: &Identifier, destination: &Identifier) -> Result<()> {
+        self.capabilities.check(capabilities::RENAME_VIEW)?;
+        let request = models::RenameTableRequest::new(
+            table_identifier(source),
+            table_identifier(destination),
+        );
+        catalog_api_api::rename_view(&self.configuration, &self.prefix, request)
+            .await
+            .map_err(anyhow::Error::msg)
+    }
+
+    /// Create the view described by `metadata`.
+    pub(crate) async fn register_view(
+        &self,
+        identifier: &Identifier,
+        metadata: &ViewMetadata,
+    ) -> Result<()> {
+        self.capabilities.check(capabilities::CREATE_VIEW)?;
+        catalog_api_api::create_view(
+            &self.configuration,
+            &self.prefix,
+            &identifier.namespace().to_string(),
+            create_view_request(identifier, metadata)?,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Make the current version of `metadata` the current version of the view.
+    pub(crate) async fn commit_view(
+        &self,
+        identifier: &Identifier,
+        metadata: &ViewMetadata,
+    ) -> Result<()> {
+        self.capabilities.check(capabilities::REPLACE_VIEW)?;
+        catalog_api_api::replace_view(
+            &self.configuration,
+            &self.prefix,
+            &identifier.namespace().to_string(),
+            identifier.name(),
+            commit_view_request(metadata)?,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn current_version(metadata: &ViewMetadata) -> Result<(&models::ViewVersion, &models::Schema)> {
+    let version = metadata
+        .current_version()
+        .ok_or_else(|| anyhow!("View metadata has no current version."))?;
+    let schema = metadata
+        .current_schema()
+        .ok_or_else(|| anyhow!("View metadata has no schema for the current version."))?;
+    Ok((version, schema))
+}
+
+fn create_view_request(
+    identifier: &Identifier,
+    metadata: &ViewMetadata,
+) -> Result<CreateViewRequest> {
+    let (version, schema) = current_version(metadata)?;
+    let mut request = CreateViewRequest::new(
+        identifier.name().to_owned(),
+        schema.clone(),
+        version.clone(),
+    );
+    request.location = Some(metadata.location.clone());
+    request.properties = metadata.properties.clone().unwrap_or_default();
+    Ok(request)
+}
+
+fn commit_view_request(metadata: &ViewMetadata) -> Result<CommitViewRequest> {
+    let (version, schema) = current_version(metadata)?;
+    let mut version = version.clone();
+    // The schema is added by this commit, the catalog assigns its id
+    version.schema_id = -1;
+    let mut updates = vec![
+        ViewUpdate::AddSchema {
+            schema: Box::new(schema.clone()),
+            last_column_id: None,
+        },
+        ViewUpdate::AddViewVersion {
+            view_version: Box::new(version),
+        },
+        ViewUpdate::SetCurrentViewVersion {
+            view_version_id: -1,
+        },
+    ];
+    if let Some(properties) = metadata.properties.as_ref().filter(|x| !x.is_empty()) {
+        updates.push(ViewUpdate::SetProperties {
+            updates: properties.clone(),
+        });
+    }
+    let mut request = CommitViewRequest::new(updates);
+    request.requirements = Some(vec![ViewRequirement::AssertViewUuid {
+        uuid: metadata.view_uuid.clone(),
+    }]);
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg_rs::catalog::identifier::Identifier;
+
+    use super::{commit_view_request, create_view_request};
+    use crate::models::{ViewMetadata, ViewRepresentation, ViewUpdate};
+
+    const METADATA: &str = r#"{
+        "view-uuid": "fa6506c3-7681-40c8-86dc-e36561f83385",
+        "format-version": 1,
+        "location": "s3://bucket/warehouse/default.db/event_agg",
+        "current-version-id": 2,
+        "properties": {"comment": "Daily event counts"},
+        "versions": [
+            {"version-id": 1, "timestamp-ms": 1573518431292, "schema-id": 1, "summary": {"engine-name": "Spark"}, "default-namespace": ["default"],
+             "representations": [{"type": "sql", "sql": "SELECT COUNT(1) FROM events", "dialect": "spark"}]},
+            {"version-id": 2, "timestamp-ms": 1573518981593, "schema-id": 2, "summary": {"engine-name": "Spark"}, "default-namespace": ["default"],
+             "representations": [{"type": "sql", "sql": "SELECT COUNT(1), CAST(event_ts AS DATE) FROM events GROUP BY 2", "dialect": "spark"}]}
+        ],
+        "version-log": [{"version-id": 1, "timestamp-ms": 1573518431292}, {"version-id": 2, "timestamp-ms": 1573518981593}],
+        "schemas": [
+            {"type": "struct", "schema-id": 1, "fields": [{"id": 1, "name": "event_count", "required": false, "type": "int"}]},
+            {"type": "struct", "schema-id": 2, "fields": [{"id": 1, "name": "event_count", "required": false, "type": "int"},
+                                                          {"id": 2, "name": "event_date", "required": false, "type": "date"}]}
+        ]
+    }"#;
+
+    #[test]
+    fn test_view_requests() {
+        let metadata: ViewMetadata = serde_json::from_str(METADATA).unwrap();
+        let identifier = Identifier::parse("default.event_agg").unwrap();
+
+        let request = create_view_request(&identifier, &metadata).unwrap();
+        assert_eq!(request.name, "event_agg");
+        assert_eq!(request.view_version.version_id, 2);
+        assert_eq!(request.schema.schema_id, Some(2));
+        assert!(matches!(
+            &request.view_version.representations[0],
+            ViewRepresentation::Sql(x) if x.dialect == "spark"
+        ));
+
+        let request = commit_view_request(&metadata).unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["requirements"][0]["type"], "assert-view-uuid");
+        assert_eq!(json["updates"][0]["action"], "add-schema");
+        assert_eq!(json["updates"][1]["view-version"]["schema-id"], -1);
+        assert_eq!(
+            request.updates[2],
+            ViewUpdate::SetCurrentViewVersion {
+                view_version_id: -1
+            }
+        );
+    }
+}
@@ -10,3 +10,7 @@ pub mod apis;
 pub mod models;
 
 pub mod catalog;
+pub mod secret;
+
+#[cfg(test)]
+mod test_util;
@@ -16,18 +16,22 @@
 pub struct CatalogConfig {
     /// Properties that should be used to override client configuration; applied after defaults and client configuration.
     #[serde(rename = "overrides")]
-    pub overrides: serde_json::Value,
+    pub overrides: ::std::collections::HashMap<String, String>,
     /// Properties that should be used as default configuration; applied before client configuration.
     #[serde(rename = "defaults")]
-    pub defaults: serde_json::Value,
+    pub defaults: ::std::collections::HashMap<String, String>,
+    /// Endpoints supported by the server, like `POST /v1/{prefix}/namespaces/{namespace}/register`
+    #[serde(rename = "endpoints", skip_serializing_if = "Option::is_none")]
+    pub endpoints: Option<Vec<String>>,
 }
 
 impl CatalogConfig {
     /// Server-provided configuration for the catalog.
-    pub fn new(overrides: serde_json::Value, defaults: serde_json::Value) -> CatalogConfig {
+    pub fn new(overrides: ::std::collections::HashMap<String, String>, defaults: ::std::collections::HashMap<String, String>) -> CatalogConfig {
         CatalogConfig {
             overrides,
             defaults,
+            endpoints: None,
         }
     }
 }
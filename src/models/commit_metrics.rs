@@ -0,0 +1,72 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+/// Well-known metrics of a commit report. Metrics that were not measured are omitted.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CommitMetrics {
+    #[serde(rename = "total-duration", skip_serializing_if = "Option::is_none")]
+    pub total_duration: Option<crate::models::TimerResult>,
+    #[serde(rename = "attempts", skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-data-files", skip_serializing_if = "Option::is_none")]
+    pub added_data_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-data-files", skip_serializing_if = "Option::is_none")]
+    pub removed_data_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-data-files", skip_serializing_if = "Option::is_none")]
+    pub total_data_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-delete-files", skip_serializing_if = "Option::is_none")]
+    pub added_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-equality-delete-files", skip_serializing_if = "Option::is_none")]
+    pub added_equality_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-positional-delete-files", skip_serializing_if = "Option::is_none")]
+    pub added_positional_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-delete-files", skip_serializing_if = "Option::is_none")]
+    pub removed_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-equality-delete-files", skip_serializing_if = "Option::is_none")]
+    pub removed_equality_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-positional-delete-files", skip_serializing_if = "Option::is_none")]
+    pub removed_positional_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-delete-files", skip_serializing_if = "Option::is_none")]
+    pub total_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-records", skip_serializing_if = "Option::is_none")]
+    pub added_records: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-records", skip_serializing_if = "Option::is_none")]
+    pub removed_records: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-records", skip_serializing_if = "Option::is_none")]
+    pub total_records: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-files-size-in-bytes", skip_serializing_if = "Option::is_none")]
+    pub added_files_size_in_bytes: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-files-size-in-bytes", skip_serializing_if = "Option::is_none")]
+    pub removed_files_size_in_bytes: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-files-size-in-bytes", skip_serializing_if = "Option::is_none")]
+    pub total_files_size_in_bytes: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-positional-deletes", skip_serializing_if = "Option::is_none")]
+    pub added_positional_deletes: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-positional-deletes", skip_serializing_if = "Option::is_none")]
+    pub removed_positional_deletes: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-positional-deletes", skip_serializing_if = "Option::is_none")]
+    pub total_positional_deletes: Option<crate::models::CounterResult>,
+    #[serde(rename = "added-equality-deletes", skip_serializing_if = "Option::is_none")]
+    pub added_equality_deletes: Option<crate::models::CounterResult>,
+    #[serde(rename = "removed-equality-deletes", skip_serializing_if = "Option::is_none")]
+    pub removed_equality_deletes: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-equality-deletes", skip_serializing_if = "Option::is_none")]
+    pub total_equality_deletes: Option<crate::models::CounterResult>,
+}
+
+impl CommitMetrics {
+    pub fn new() -> CommitMetrics {
+        CommitMetrics::default()
+    }
+}
+
+
@@ -13,6 +13,9 @@
 
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct CommitTableRequest {
+    /// Table to change, required if the request is part of a transaction
+    #[serde(rename = "identifier", skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<Box<crate::models::TableIdentifier>>,
     #[serde(rename = "requirements")]
     pub requirements: Vec<crate::models::TableRequirement>,
     #[serde(rename = "updates")]
@@ -22,6 +25,7 @@ pub struct CommitTableRequest {
 impl CommitTableRequest {
     pub fn new(requirements: Vec<crate::models::TableRequirement>, updates: Vec<crate::models::TableUpdate>) -> CommitTableRequest {
         CommitTableRequest {
+            identifier: None,
             requirements,
             updates,
         }
@@ -0,0 +1,26 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CommitTransactionRequest {
+    /// Changes of all tables of the transaction, each with the identifier of its table
+    #[serde(rename = "table-changes")]
+    pub table_changes: Vec<crate::models::CommitTableRequest>,
+}
+
+impl CommitTransactionRequest {
+    pub fn new(table_changes: Vec<crate::models::CommitTableRequest>) -> CommitTransactionRequest {
+        CommitTransactionRequest {
+            table_changes,
+        }
+    }
+}
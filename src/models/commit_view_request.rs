@@ -0,0 +1,28 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CommitViewRequest {
+    #[serde(rename = "requirements", skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<Vec<crate::models::ViewRequirement>>,
+    #[serde(rename = "updates")]
+    pub updates: Vec<crate::models::ViewUpdate>,
+}
+
+impl CommitViewRequest {
+    pub fn new(updates: Vec<crate::models::ViewUpdate>) -> CommitViewRequest {
+        CommitViewRequest {
+            requirements: None,
+            updates,
+        }
+    }
+}
@@ -26,6 +26,16 @@ impl CounterResult {
             value,
         }
     }
+
+    /// Counter of items, like files or records.
+    pub fn count(value: i64) -> CounterResult {
+        CounterResult::new("count".to_owned(), value)
+    }
+
+    /// Counter of a size in bytes.
+    pub fn bytes(value: i64) -> CounterResult {
+        CounterResult::new("bytes".to_owned(), value)
+    }
 }
 
 
@@ -15,7 +15,7 @@
 pub struct GetToken200Response {
     /// The access token, for client credentials or token exchange
     #[serde(rename = "access_token")]
-    pub access_token: String,
+    pub access_token: crate::secret::Secret,
     /// Access token type for client credentials or token exchange  See https://datatracker.ietf.org/doc/html/rfc6749#section-7.1
     #[serde(rename = "token_type")]
     pub token_type: TokenType,
@@ -26,14 +26,14 @@ pub struct GetToken200Response {
     pub issued_token_type: Option<crate::models::TokenType>,
     /// Refresh token for client credentials or token exchange
     #[serde(rename = "refresh_token", skip_serializing_if = "Option::is_none")]
-    pub refresh_token: Option<String>,
+    pub refresh_token: Option<crate::secret::Secret>,
     /// Authorization scope for client credentials or token exchange
     #[serde(rename = "scope", skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
 }
 
 impl GetToken200Response {
-    pub fn new(access_token: String, token_type: TokenType) -> GetToken200Response {
+    pub fn new(access_token: crate::secret::Secret, token_type: TokenType) -> GetToken200Response {
         GetToken200Response {
             access_token,
             token_type,
@@ -46,14 +46,43 @@ impl GetToken200Response {
 }
 
 /// Access token type for client credentials or token exchange  See https://datatracker.ietf.org/doc/html/rfc6749#section-7.1
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+/// Token types are case insensitive, unknown types are kept as `Other`.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum TokenType {
-    #[serde(rename = "bearer")]
     Bearer,
-    #[serde(rename = "mac")]
     Mac,
-    #[serde(rename = "N_A")]
+    /// The issued token is not an access token, see RFC 8693
     NA,
+    Other(String),
+}
+
+impl From<String> for TokenType {
+    fn from(token_type: String) -> Self {
+        match token_type.to_ascii_lowercase().as_str() {
+            "bearer" => Self::Bearer,
+            "mac" => Self::Mac,
+            "n_a" => Self::NA,
+            _ => Self::Other(token_type),
+        }
+    }
+}
+
+impl From<TokenType> for String {
+    fn from(token_type: TokenType) -> Self {
+        token_type.to_string()
+    }
+}
+
+impl std::fmt::Display for TokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bearer => write!(f, "bearer"),
+            Self::Mac => write!(f, "mac"),
+            Self::NA => write!(f, "N_A"),
+            Self::Other(token_type) => write!(f, "{}", token_type),
+        }
+    }
 }
 
 impl Default for TokenType {
@@ -31,21 +31,55 @@ impl GetToken400Response {
     }
 }
 
-/// 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+/// Error code of the token endpoint. Codes of extensions, like the token exchange, are kept as `Other`.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum Error {
-    #[serde(rename = "invalid_request")]
     InvalidRequest,
-    #[serde(rename = "invalid_client")]
     InvalidClient,
-    #[serde(rename = "invalid_grant")]
     InvalidGrant,
-    #[serde(rename = "unauthorized_client")]
     UnauthorizedClient,
-    #[serde(rename = "unsupported_grant_type")]
     UnsupportedGrantType,
-    #[serde(rename = "invalid_scope")]
     InvalidScope,
+    /// The requested audience or resource of a token exchange is invalid, see RFC 8693
+    InvalidTarget,
+    Other(String),
+}
+
+impl From<String> for Error {
+    fn from(error: String) -> Self {
+        match error.as_str() {
+            "invalid_request" => Self::InvalidRequest,
+            "invalid_client" => Self::InvalidClient,
+            "invalid_grant" => Self::InvalidGrant,
+            "unauthorized_client" => Self::UnauthorizedClient,
+            "unsupported_grant_type" => Self::UnsupportedGrantType,
+            "invalid_scope" => Self::InvalidScope,
+            "invalid_target" => Self::InvalidTarget,
+            _ => Self::Other(error),
+        }
+    }
+}
+
+impl From<Error> for String {
+    fn from(error: Error) -> Self {
+        error.to_string()
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidRequest => write!(f, "invalid_request"),
+            Self::InvalidClient => write!(f, "invalid_client"),
+            Self::InvalidGrant => write!(f, "invalid_grant"),
+            Self::UnauthorizedClient => write!(f, "unauthorized_client"),
+            Self::UnsupportedGrantType => write!(f, "unsupported_grant_type"),
+            Self::InvalidScope => write!(f, "invalid_scope"),
+            Self::InvalidTarget => write!(f, "invalid_target"),
+            Self::Other(error) => write!(f, "{}", error),
+        }
+    }
 }
 
 impl Default for Error {
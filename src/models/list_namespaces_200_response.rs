@@ -12,10 +12,16 @@
 pub struct ListNamespaces200Response {
     #[serde(rename = "namespaces", skip_serializing_if = "Option::is_none")]
     pub namespaces: Option<Vec<Vec<String>>>,
+    /// Token of the next page, only set by servers that paginate the listing.
+    #[serde(rename = "next-page-token", skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
 }
 
 impl ListNamespaces200Response {
     pub fn new() -> ListNamespaces200Response {
-        ListNamespaces200Response { namespaces: None }
+        ListNamespaces200Response {
+            namespaces: None,
+            next_page_token: None,
+        }
     }
 }
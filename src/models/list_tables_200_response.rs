@@ -15,12 +15,16 @@
 pub struct ListTables200Response {
     #[serde(rename = "identifiers", skip_serializing_if = "Option::is_none")]
     pub identifiers: Option<Vec<crate::models::TableIdentifier>>,
+    /// Token of the next page, only set by servers that paginate the listing.
+    #[serde(rename = "next-page-token", skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
 }
 
 impl ListTables200Response {
     pub fn new() -> ListTables200Response {
         ListTables200Response {
             identifiers: None,
+            next_page_token: None,
         }
     }
 }
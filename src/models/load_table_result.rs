@@ -21,6 +21,9 @@ pub struct LoadTableResult {
     pub metadata: Box<crate::models::TableMetadata>,
     #[serde(rename = "config", skip_serializing_if = "Option::is_none")]
     pub config: Option<::std::collections::HashMap<String, String>>,
+    /// ETag of the response, to check whether the table changed with `If-None-Match`. Not part of the JSON body
+    #[serde(skip)]
+    pub etag: Option<String>,
 }
 
 impl LoadTableResult {
@@ -30,6 +33,7 @@ impl LoadTableResult {
             metadata_location: None,
             metadata: Box::new(metadata),
             config: None,
+            etag: None,
         }
     }
 }
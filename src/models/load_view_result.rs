@@ -0,0 +1,32 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LoadViewResult {
+    #[serde(rename = "metadata-location")]
+    pub metadata_location: String,
+    #[serde(rename = "metadata")]
+    pub metadata: Box<crate::models::ViewMetadata>,
+    #[serde(rename = "config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl LoadViewResult {
+    /// Result used when a view is successfully loaded.  The view metadata JSON is returned in the `metadata` field. The corresponding file location of view metadata is returned in the `metadata-location` field.  The `config` map returns view-specific configuration for the view's resources.
+    pub fn new(metadata_location: String, metadata: crate::models::ViewMetadata) -> LoadViewResult {
+        LoadViewResult {
+            metadata_location,
+            metadata: Box::new(metadata),
+            config: None,
+        }
+    }
+}
@@ -11,29 +11,23 @@
 
 
 
-#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
-pub struct MetricResult {
-    #[serde(rename = "unit")]
-    pub unit: String,
-    #[serde(rename = "value")]
-    pub value: i64,
-    #[serde(rename = "time-unit")]
-    pub time_unit: String,
-    #[serde(rename = "count")]
-    pub count: i64,
-    #[serde(rename = "total-duration")]
-    pub total_duration: i64,
+/// Result of a metric, either a counter or a timer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MetricResult {
+    CounterResult(crate::models::CounterResult),
+    TimerResult(crate::models::TimerResult),
 }
 
-impl MetricResult {
-    pub fn new(unit: String, value: i64, time_unit: String, count: i64, total_duration: i64) -> MetricResult {
-        MetricResult {
-            unit,
-            value,
-            time_unit,
-            count,
-            total_duration,
-        }
+impl From<crate::models::CounterResult> for MetricResult {
+    fn from(result: crate::models::CounterResult) -> Self {
+        MetricResult::CounterResult(result)
+    }
+}
+
+impl From<crate::models::TimerResult> for MetricResult {
+    fn from(result: crate::models::TimerResult) -> Self {
+        MetricResult::TimerResult(result)
     }
 }
 
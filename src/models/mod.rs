@@ -20,8 +20,16 @@ pub mod base_update;
 pub use self::base_update::BaseUpdate;
 pub mod catalog_config;
 pub use self::catalog_config::CatalogConfig;
+pub mod commit_metrics;
+pub use self::commit_metrics::CommitMetrics;
+pub mod commit_report;
+pub use self::commit_report::CommitReport;
 pub mod commit_table_request;
 pub use self::commit_table_request::CommitTableRequest;
+pub mod commit_transaction_request;
+pub use self::commit_transaction_request::CommitTransactionRequest;
+pub mod commit_view_request;
+pub use self::commit_view_request::CommitViewRequest;
 pub mod counter_result;
 pub use self::counter_result::CounterResult;
 pub mod create_namespace_200_response;
@@ -30,6 +38,8 @@ pub mod create_namespace_request;
 pub use self::create_namespace_request::CreateNamespaceRequest;
 pub mod create_table_request;
 pub use self::create_table_request::CreateTableRequest;
+pub mod create_view_request;
+pub use self::create_view_request::CreateViewRequest;
 pub mod error_model;
 pub use self::error_model::ErrorModel;
 pub mod expression;
@@ -50,6 +60,8 @@ pub mod load_namespace_metadata_200_response;
 pub use self::load_namespace_metadata_200_response::LoadNamespaceMetadata200Response;
 pub mod load_table_result;
 pub use self::load_table_result::LoadTableResult;
+pub mod load_view_result;
+pub use self::load_view_result::LoadViewResult;
 pub mod map_type;
 pub use self::map_type::MapType;
 pub mod metadata_log_inner;
@@ -64,6 +76,8 @@ pub mod partition_field;
 pub use self::partition_field::PartitionField;
 pub mod partition_spec;
 pub use self::partition_spec::PartitionSpec;
+pub mod register_table_request;
+pub use self::register_table_request::RegisterTableRequest;
 pub mod remove_properties_update;
 pub use self::remove_properties_update::RemovePropertiesUpdate;
 pub mod remove_properties_update_all_of;
@@ -78,6 +92,8 @@ pub mod rename_table_request;
 pub use self::rename_table_request::RenameTableRequest;
 pub mod report_metrics_request;
 pub use self::report_metrics_request::ReportMetricsRequest;
+pub mod scan_metrics;
+pub use self::scan_metrics::ScanMetrics;
 pub mod scan_report;
 pub use self::scan_report::ScanReport;
 pub mod schema;
@@ -124,6 +140,8 @@ pub mod sort_field;
 pub use self::sort_field::SortField;
 pub mod sort_order;
 pub use self::sort_order::SortOrder;
+pub mod sql_view_representation;
+pub use self::sql_view_representation::SqlViewRepresentation;
 pub mod struct_field;
 pub use self::struct_field::StructField;
 pub mod struct_type;
@@ -158,3 +176,15 @@ pub mod upgrade_format_version_update;
 pub use self::upgrade_format_version_update::UpgradeFormatVersionUpdate;
 pub mod upgrade_format_version_update_all_of;
 pub use self::upgrade_format_version_update_all_of::UpgradeFormatVersionUpdateAllOf;
+pub mod view_history_entry;
+pub use self::view_history_entry::ViewHistoryEntry;
+pub mod view_metadata;
+pub use self::view_metadata::ViewMetadata;
+pub mod view_representation;
+pub use self::view_representation::ViewRepresentation;
+pub mod view_requirement;
+pub use self::view_requirement::ViewRequirement;
+pub mod view_update;
+pub use self::view_update::ViewUpdate;
+pub mod view_version;
+pub use self::view_version::ViewVersion;
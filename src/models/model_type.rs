@@ -10,58 +10,36 @@
 
 
 
-
-#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
-pub struct Type {
-    #[serde(rename = "type")]
-    pub r#type: RHashType,
-    #[serde(rename = "fields")]
-    pub fields: Vec<crate::models::StructField>,
-    #[serde(rename = "element-id")]
-    pub element_id: i32,
-    #[serde(rename = "element")]
-    pub element: Box<crate::models::Type>,
-    #[serde(rename = "element-required")]
-    pub element_required: bool,
-    #[serde(rename = "key-id")]
-    pub key_id: i32,
-    #[serde(rename = "key")]
-    pub key: Box<crate::models::Type>,
-    #[serde(rename = "value-id")]
-    pub value_id: i32,
-    #[serde(rename = "value")]
-    pub value: Box<crate::models::Type>,
-    #[serde(rename = "value-required")]
-    pub value_required: bool,
+/// Type of a schema field: a primitive type name like `long` or `decimal(9,2)`, or a nested type.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Type {
+    Primitive(String),
+    Struct(crate::models::StructType),
+    List(crate::models::ListType),
+    Map(crate::models::MapType),
 }
 
-impl Type {
-    pub fn new(r#type: RHashType, fields: Vec<crate::models::StructField>, element_id: i32, element: crate::models::Type, element_required: bool, key_id: i32, key: crate::models::Type, value_id: i32, value: crate::models::Type, value_required: bool) -> Type {
-        Type {
-            r#type,
-            fields,
-            element_id,
-            element: Box::new(element),
-            element_required,
-            key_id,
-            key: Box::new(key),
-            value_id,
-            value: Box::new(value),
-            value_required,
-        }
+impl Default for Type {
+    fn default() -> Type {
+        Self::Struct(crate::models::StructType::default())
     }
 }
 
-/// 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
-pub enum RHashType {
-    #[serde(rename = "map")]
-    Map,
+impl From<crate::models::StructType> for Type {
+    fn from(r#type: crate::models::StructType) -> Self {
+        Type::Struct(r#type)
+    }
 }
 
-impl Default for RHashType {
-    fn default() -> RHashType {
-        Self::Map
+impl From<crate::models::ListType> for Type {
+    fn from(r#type: crate::models::ListType) -> Self {
+        Type::List(r#type)
     }
 }
 
+impl From<crate::models::MapType> for Type {
+    fn from(r#type: crate::models::MapType) -> Self {
+        Type::Map(r#type)
+    }
+}
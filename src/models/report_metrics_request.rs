@@ -10,34 +10,35 @@
 
 
 
-
-#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
-pub struct ReportMetricsRequest {
-    #[serde(rename = "report-type")]
-    pub report_type: String,
-    #[serde(rename = "table-name")]
-    pub table_name: String,
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: i64,
-    #[serde(rename = "filter")]
-    pub filter: Box<crate::models::Expression>,
-    #[serde(rename = "projection")]
-    pub projection: Box<crate::models::Schema>,
-    #[serde(rename = "metrics")]
-    pub metrics: ::std::collections::HashMap<String, crate::models::MetricResult>,
+/// Metrics report of a table operation, tagged by its `report-type`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "report-type")]
+pub enum ReportMetricsRequest {
+    #[serde(rename = "scan-report")]
+    ScanReport(crate::models::ScanReport),
+    #[serde(rename = "commit-report")]
+    CommitReport(crate::models::CommitReport),
 }
 
 impl ReportMetricsRequest {
-    pub fn new(report_type: String, table_name: String, snapshot_id: i64, filter: crate::models::Expression, projection: crate::models::Schema, metrics: ::std::collections::HashMap<String, crate::models::MetricResult>) -> ReportMetricsRequest {
-        ReportMetricsRequest {
-            report_type,
-            table_name,
-            snapshot_id,
-            filter: Box::new(filter),
-            projection: Box::new(projection),
-            metrics,
+    pub fn table_name(&self) -> &str {
+        match self {
+            ReportMetricsRequest::ScanReport(report) => &report.table_name,
+            ReportMetricsRequest::CommitReport(report) => &report.table_name,
         }
     }
 }
 
+impl From<crate::models::ScanReport> for ReportMetricsRequest {
+    fn from(report: crate::models::ScanReport) -> Self {
+        ReportMetricsRequest::ScanReport(report)
+    }
+}
+
+impl From<crate::models::CommitReport> for ReportMetricsRequest {
+    fn from(report: crate::models::CommitReport) -> Self {
+        ReportMetricsRequest::CommitReport(report)
+    }
+}
+
 
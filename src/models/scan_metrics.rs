@@ -0,0 +1,56 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+/// Well-known metrics of a scan report. Metrics that were not measured are omitted.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScanMetrics {
+    #[serde(rename = "total-planning-duration", skip_serializing_if = "Option::is_none")]
+    pub total_planning_duration: Option<crate::models::TimerResult>,
+    #[serde(rename = "result-data-files", skip_serializing_if = "Option::is_none")]
+    pub result_data_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "result-delete-files", skip_serializing_if = "Option::is_none")]
+    pub result_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-data-manifests", skip_serializing_if = "Option::is_none")]
+    pub total_data_manifests: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-delete-manifests", skip_serializing_if = "Option::is_none")]
+    pub total_delete_manifests: Option<crate::models::CounterResult>,
+    #[serde(rename = "scanned-data-manifests", skip_serializing_if = "Option::is_none")]
+    pub scanned_data_manifests: Option<crate::models::CounterResult>,
+    #[serde(rename = "skipped-data-manifests", skip_serializing_if = "Option::is_none")]
+    pub skipped_data_manifests: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-file-size-in-bytes", skip_serializing_if = "Option::is_none")]
+    pub total_file_size_in_bytes: Option<crate::models::CounterResult>,
+    #[serde(rename = "total-delete-file-size-in-bytes", skip_serializing_if = "Option::is_none")]
+    pub total_delete_file_size_in_bytes: Option<crate::models::CounterResult>,
+    #[serde(rename = "skipped-data-files", skip_serializing_if = "Option::is_none")]
+    pub skipped_data_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "skipped-delete-files", skip_serializing_if = "Option::is_none")]
+    pub skipped_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "scanned-delete-manifests", skip_serializing_if = "Option::is_none")]
+    pub scanned_delete_manifests: Option<crate::models::CounterResult>,
+    #[serde(rename = "skipped-delete-manifests", skip_serializing_if = "Option::is_none")]
+    pub skipped_delete_manifests: Option<crate::models::CounterResult>,
+    #[serde(rename = "indexed-delete-files", skip_serializing_if = "Option::is_none")]
+    pub indexed_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "equality-delete-files", skip_serializing_if = "Option::is_none")]
+    pub equality_delete_files: Option<crate::models::CounterResult>,
+    #[serde(rename = "positional-delete-files", skip_serializing_if = "Option::is_none")]
+    pub positional_delete_files: Option<crate::models::CounterResult>,
+}
+
+impl ScanMetrics {
+    pub fn new() -> ScanMetrics {
+        ScanMetrics::default()
+    }
+}
+
+
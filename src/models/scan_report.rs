@@ -22,17 +22,17 @@ pub struct ScanReport {
     #[serde(rename = "projection")]
     pub projection: Box<crate::models::Schema>,
     #[serde(rename = "metrics")]
-    pub metrics: ::std::collections::HashMap<String, crate::models::MetricResult>,
+    pub metrics: Box<crate::models::ScanMetrics>,
 }
 
 impl ScanReport {
-    pub fn new(table_name: String, snapshot_id: i64, filter: crate::models::Expression, projection: crate::models::Schema, metrics: ::std::collections::HashMap<String, crate::models::MetricResult>) -> ScanReport {
+    pub fn new(table_name: String, snapshot_id: i64, filter: crate::models::Expression, projection: crate::models::Schema, metrics: crate::models::ScanMetrics) -> ScanReport {
         ScanReport {
             table_name,
             snapshot_id,
             filter: Box::new(filter),
             projection: Box::new(projection),
-            metrics,
+            metrics: Box::new(metrics),
         }
     }
 }
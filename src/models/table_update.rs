@@ -11,73 +11,74 @@
 
 
 
+/// Update of a table, identified by its `action`. Only the fields of the action are set.
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct TableUpdate {
     #[serde(rename = "action")]
     pub action: Action,
-    #[serde(rename = "format-version")]
-    pub format_version: i32,
-    #[serde(rename = "schema")]
-    pub schema: Box<crate::models::Schema>,
+    #[serde(rename = "format-version", skip_serializing_if = "Option::is_none")]
+    pub format_version: Option<i32>,
+    #[serde(rename = "schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Box<crate::models::Schema>>,
     /// Schema ID to set as current, or -1 to set last added schema
-    #[serde(rename = "schema-id")]
-    pub schema_id: i32,
-    #[serde(rename = "spec")]
-    pub spec: Box<crate::models::PartitionSpec>,
+    #[serde(rename = "schema-id", skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    #[serde(rename = "spec", skip_serializing_if = "Option::is_none")]
+    pub spec: Option<Box<crate::models::PartitionSpec>>,
     /// Partition spec ID to set as the default, or -1 to set last added spec
-    #[serde(rename = "spec-id")]
-    pub spec_id: i32,
-    #[serde(rename = "sort-order")]
-    pub sort_order: Box<crate::models::SortOrder>,
+    #[serde(rename = "spec-id", skip_serializing_if = "Option::is_none")]
+    pub spec_id: Option<i32>,
+    #[serde(rename = "sort-order", skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<Box<crate::models::SortOrder>>,
     /// Sort order ID to set as the default, or -1 to set last added sort order
-    #[serde(rename = "sort-order-id")]
-    pub sort_order_id: i32,
-    #[serde(rename = "snapshot")]
-    pub snapshot: Box<crate::models::Snapshot>,
-    #[serde(rename = "type")]
-    pub r#type: RHashType,
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: i64,
+    #[serde(rename = "sort-order-id", skip_serializing_if = "Option::is_none")]
+    pub sort_order_id: Option<i32>,
+    #[serde(rename = "snapshot", skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<Box<crate::models::Snapshot>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<RHashType>,
+    #[serde(rename = "snapshot-id", skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<i64>,
     #[serde(rename = "max-ref-age-ms", skip_serializing_if = "Option::is_none")]
     pub max_ref_age_ms: Option<i64>,
     #[serde(rename = "max-snapshot-age-ms", skip_serializing_if = "Option::is_none")]
     pub max_snapshot_age_ms: Option<i64>,
     #[serde(rename = "min-snapshots-to-keep", skip_serializing_if = "Option::is_none")]
     pub min_snapshots_to_keep: Option<i32>,
-    #[serde(rename = "ref-name")]
-    pub ref_name: String,
-    #[serde(rename = "snapshot-ids")]
-    pub snapshot_ids: Vec<i64>,
-    #[serde(rename = "location")]
-    pub location: String,
-    #[serde(rename = "updates")]
-    pub updates: ::std::collections::HashMap<String, String>,
-    #[serde(rename = "removals")]
-    pub removals: Vec<String>,
+    #[serde(rename = "ref-name", skip_serializing_if = "Option::is_none")]
+    pub ref_name: Option<String>,
+    #[serde(rename = "snapshot-ids", skip_serializing_if = "Option::is_none")]
+    pub snapshot_ids: Option<Vec<i64>>,
+    #[serde(rename = "location", skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(rename = "updates", skip_serializing_if = "Option::is_none")]
+    pub updates: Option<::std::collections::HashMap<String, String>>,
+    #[serde(rename = "removals", skip_serializing_if = "Option::is_none")]
+    pub removals: Option<Vec<String>>,
 }
 
 impl TableUpdate {
-    pub fn new(action: Action, format_version: i32, schema: crate::models::Schema, schema_id: i32, spec: crate::models::PartitionSpec, spec_id: i32, sort_order: crate::models::SortOrder, sort_order_id: i32, snapshot: crate::models::Snapshot, r#type: RHashType, snapshot_id: i64, ref_name: String, snapshot_ids: Vec<i64>, location: String, updates: ::std::collections::HashMap<String, String>, removals: Vec<String>) -> TableUpdate {
+    pub fn new(action: Action) -> TableUpdate {
         TableUpdate {
             action,
-            format_version,
-            schema: Box::new(schema),
-            schema_id,
-            spec: Box::new(spec),
-            spec_id,
-            sort_order: Box::new(sort_order),
-            sort_order_id,
-            snapshot: Box::new(snapshot),
-            r#type,
-            snapshot_id,
+            format_version: None,
+            schema: None,
+            schema_id: None,
+            spec: None,
+            spec_id: None,
+            sort_order: None,
+            sort_order_id: None,
+            snapshot: None,
+            r#type: None,
+            snapshot_id: None,
             max_ref_age_ms: None,
             max_snapshot_age_ms: None,
             min_snapshots_to_keep: None,
-            ref_name,
-            snapshot_ids,
-            location,
-            updates,
-            removals,
+            ref_name: None,
+            snapshot_ids: None,
+            location: None,
+            updates: None,
+            removals: None,
         }
     }
 }
@@ -8,8 +8,18 @@
  * Generated by: https://openapi-generator.tech
  */
 
+use std::{convert::TryFrom, time::Duration};
 
-
+/// Time units accepted by the catalog, with their length in nanoseconds, from the coarsest to the finest.
+const TIME_UNITS: [(&str, u128); 7] = [
+    ("days", 86_400_000_000_000),
+    ("hours", 3_600_000_000_000),
+    ("minutes", 60_000_000_000),
+    ("seconds", 1_000_000_000),
+    ("milliseconds", 1_000_000),
+    ("microseconds", 1_000),
+    ("nanoseconds", 1),
+];
 
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct TimerResult {
@@ -29,6 +39,54 @@ impl TimerResult {
             total_duration,
         }
     }
+
+    /// Timer of `count` measurements that took `total_duration` together. The duration is expressed
+    /// in the coarsest time unit that represents it exactly.
+    pub fn from_duration(count: i64, total_duration: Duration) -> TimerResult {
+        let nanos = total_duration.as_nanos();
+        let (time_unit, unit_nanos) = TIME_UNITS
+            .iter()
+            .find(|(_, unit_nanos)| nanos > 0 && nanos % unit_nanos == 0)
+            .copied()
+            .unwrap_or(("nanoseconds", 1));
+        let duration = i64::try_from(nanos / unit_nanos).unwrap_or(i64::MAX);
+        TimerResult::new(time_unit.to_owned(), count, duration)
+    }
+
+    /// Total duration of the measurements. Returns `None` for an unknown time unit.
+    pub fn duration(&self) -> Option<Duration> {
+        let (_, unit_nanos) = TIME_UNITS
+            .iter()
+            .find(|(time_unit, _)| time_unit.eq_ignore_ascii_case(&self.time_unit))?;
+        let nanos = u128::try_from(self.total_duration).ok()? * unit_nanos;
+        Some(Duration::new(
+            u64::try_from(nanos / 1_000_000_000).ok()?,
+            (nanos % 1_000_000_000) as u32,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::TimerResult;
+
+    #[test]
+    fn test_from_duration() {
+        let timer = TimerResult::from_duration(1, Duration::from_millis(1500));
+        assert_eq!(timer.time_unit, "milliseconds");
+        assert_eq!(timer.total_duration, 1500);
+        assert_eq!(timer.duration(), Some(Duration::from_millis(1500)));
+
+        let timer = TimerResult::from_duration(2, Duration::from_secs(120));
+        assert_eq!(timer.time_unit, "minutes");
+        assert_eq!(timer.total_duration, 2);
+
+        let timer = TimerResult::from_duration(1, Duration::ZERO);
+        assert_eq!(timer.time_unit, "nanoseconds");
+        assert_eq!(timer.total_duration, 0);
+    }
 }
 
 
@@ -0,0 +1,28 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ViewHistoryEntry {
+    #[serde(rename = "version-id")]
+    pub version_id: i32,
+    #[serde(rename = "timestamp-ms")]
+    pub timestamp_ms: i64,
+}
+
+impl ViewHistoryEntry {
+    pub fn new(version_id: i32, timestamp_ms: i64) -> ViewHistoryEntry {
+        ViewHistoryEntry {
+            version_id,
+            timestamp_ms,
+        }
+    }
+}
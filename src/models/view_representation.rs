@@ -0,0 +1,25 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+/// Representation of a view version, tagged by its `type`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ViewRepresentation {
+    #[serde(rename = "sql")]
+    Sql(crate::models::SqlViewRepresentation),
+}
+
+impl From<crate::models::SqlViewRepresentation> for ViewRepresentation {
+    fn from(representation: crate::models::SqlViewRepresentation) -> Self {
+        ViewRepresentation::Sql(representation)
+    }
+}
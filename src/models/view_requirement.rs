@@ -0,0 +1,23 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+/// Assertion from the client that must be valid for a view commit to succeed, tagged by its `type`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ViewRequirement {
+    /// The view UUID must match the requirement's `uuid`
+    #[serde(rename = "assert-view-uuid")]
+    AssertViewUuid {
+        #[serde(rename = "uuid")]
+        uuid: String,
+    },
+}
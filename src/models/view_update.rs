@@ -0,0 +1,60 @@
+/*
+ * Apache Iceberg REST Catalog API
+ *
+ * Defines the specification for the first version of the REST Catalog API. Implementations should ideally support both Iceberg table specs v1 and v2, with priority given to v2.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+/// Change to the metadata of a view, tagged by its `action`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action")]
+pub enum ViewUpdate {
+    #[serde(rename = "assign-uuid")]
+    AssignUuid {
+        #[serde(rename = "uuid")]
+        uuid: String,
+    },
+    #[serde(rename = "upgrade-format-version")]
+    UpgradeFormatVersion {
+        #[serde(rename = "format-version")]
+        format_version: i32,
+    },
+    #[serde(rename = "add-schema")]
+    AddSchema {
+        #[serde(rename = "schema")]
+        schema: Box<crate::models::Schema>,
+        #[serde(rename = "last-column-id", skip_serializing_if = "Option::is_none")]
+        last_column_id: Option<i32>,
+    },
+    #[serde(rename = "set-location")]
+    SetLocation {
+        #[serde(rename = "location")]
+        location: String,
+    },
+    #[serde(rename = "set-properties")]
+    SetProperties {
+        #[serde(rename = "updates")]
+        updates: ::std::collections::HashMap<String, String>,
+    },
+    #[serde(rename = "remove-properties")]
+    RemoveProperties {
+        #[serde(rename = "removals")]
+        removals: Vec<String>,
+    },
+    #[serde(rename = "add-view-version")]
+    AddViewVersion {
+        #[serde(rename = "view-version")]
+        view_version: Box<crate::models::ViewVersion>,
+    },
+    /// A `view-version-id` of -1 sets the last added view version as current
+    #[serde(rename = "set-current-view-version")]
+    SetCurrentViewVersion {
+        #[serde(rename = "view-version-id")]
+        view_version_id: i32,
+    },
+}
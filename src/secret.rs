@@ -0,0 +1,66 @@
+use std::fmt;
+
+use zeroize::Zeroize;
+
+/// String holding a credential, like an access token or a password.
+///
+/// The value is never printed by `Debug` or `Display` and is zeroized when dropped.
+/// Use [`Secret::expose`] to access it.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(secret: String) -> Self {
+        Secret(secret)
+    }
+
+    /// Returns the plain value of the secret.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Secret(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Secret(secret.to_owned())
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Secret;
+
+    #[test]
+    fn test_redact() {
+        let secret = Secret::from("token");
+        assert_eq!(format!("{:?}", secret), "***");
+        assert_eq!(format!("{}", secret), "***");
+        assert_eq!(secret.expose(), "token");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), "\"token\"");
+    }
+}
@@ -0,0 +1,145 @@
+//! Helpers shared by the tests of the crate.
+
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+/// Request received by a [`ServerStub`].
+#[derive(Debug, Clone)]
+pub(crate) struct StubRequest {
+    pub method: String,
+    /// Path and query, or the authority of a `CONNECT` request.
+    pub target: String,
+    /// Header names are lowercase.
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl StubRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(x, _)| x.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// HTTP server on a local port that answers a fixed number of requests and records them.
+pub(crate) struct ServerStub {
+    pub uri: String,
+    handle: JoinHandle<Vec<StubRequest>>,
+}
+
+impl ServerStub {
+    /// Answer requests with `responses`, complete HTTP responses, in order.
+    pub async fn start(responses: Vec<String>) -> Self {
+        let mut responses = responses.into_iter();
+        let count = responses.len();
+        ServerStub::with_handler(count, move |_| responses.next().unwrap()).await
+    }
+
+    /// Answer `count` requests with the response returned by `handler`.
+    pub async fn with_handler(
+        count: usize,
+        handler: impl FnMut(&StubRequest) -> String + Send + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            serve(stream, &listener, count, handler).await
+        });
+        ServerStub { uri, handle }
+    }
+
+    /// The requests received by the stub, after it answered all of them.
+    pub async fn requests(self) -> Vec<StubRequest> {
+        self.handle.await.unwrap()
+    }
+}
+
+/// Answer `count` requests on `stream`, accepting a new connection from `listener` whenever the client closes one.
+async fn serve(
+    mut stream: TcpStream,
+    listener: &TcpListener,
+    count: usize,
+    mut handler: impl FnMut(&StubRequest) -> String,
+) -> Vec<StubRequest> {
+    let mut requests = Vec::with_capacity(count);
+    while requests.len() < count {
+        let request = match read_request(&mut stream).await {
+            Some(request) => request,
+            None => {
+                stream = listener.accept().await.unwrap().0;
+                continue;
+            }
+        };
+        stream
+            .write_all(handler(&request).as_bytes())
+            .await
+            .unwrap();
+        requests.push(request);
+    }
+    requests
+}
+
+/// Read a request, returns `None` if the connection was closed before a request was received.
+pub(crate) async fn read_request<S: AsyncRead + Unpin>(stream: &mut S) -> Option<StubRequest> {
+    let mut data = Vec::new();
+    let mut buf = [0; 4096];
+    let head_end = loop {
+        if let Some(position) = data.windows(4).position(|x| x == b"\r\n\r\n") {
+            break position;
+        }
+        let read = stream.read(&mut buf).await.unwrap_or(0);
+        if read == 0 {
+            return None;
+        }
+        data.extend_from_slice(&buf[..read]);
+    };
+    let head = String::from_utf8(data[..head_end].to_vec()).unwrap();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap().split(' ');
+    let method = request_line.next().unwrap().to_owned();
+    let target = request_line.next().unwrap().to_owned();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.to_lowercase(), value.trim().to_owned()))
+        .collect();
+    let length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .map(|(_, value)| value.parse::<usize>().unwrap())
+        .unwrap_or(0);
+    let mut body = data[head_end + 4..].to_vec();
+    while body.len() < length {
+        let read = stream.read(&mut buf).await.unwrap();
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&buf[..read]);
+    }
+    Some(StubRequest {
+        method,
+        target,
+        headers,
+        body,
+    })
+}
+
+/// A complete HTTP response with a JSON body.
+pub(crate) fn json_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+/// A complete HTTP response without a body.
+pub(crate) fn empty_response(status: &str) -> String {
+    format!("HTTP/1.1 {}\r\ncontent-length: 0\r\n\r\n", status)
+}